@@ -6,7 +6,8 @@ impl GazelleClient {
     /// # See Also
     /// - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#torrents-browse>
     pub async fn browse(&self, request: &BrowseRequest) -> Result<BrowseResponse, GazelleError> {
-        self.get(request.to_query()).await
+        self.instrument_action("browse", self.get(request.to_query()))
+            .await
     }
 }
 
@@ -0,0 +1,210 @@
+use crate::prelude::*;
+use futures::{StreamExt, stream};
+
+impl GazelleClient {
+    /// Execute a browse query and stream every matching group across all pages.
+    ///
+    /// - Starts at `request.page`, or the first page if unset
+    /// - Stops after the last page or once `max_pages` pages have been fetched
+    /// - Pages are fetched lazily as the stream is polled, so each is subject to the rate limiter
+    ///   and dropping the stream cancels any remaining requests
+    /// - Yields the error and ends the stream if a page fails
+    ///
+    /// # See Also
+    /// - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#torrents-browse>
+    pub fn browse_all<'a>(
+        &'a self,
+        request: &BrowseRequest,
+        max_pages: Option<u32>,
+    ) -> impl Stream<Item = Result<BrowseGroup, GazelleError>> + use<'a> {
+        self.browse_pages(request, max_pages)
+            .map(|page| {
+                let groups: Vec<_> = match page {
+                    Ok(response) => response.results.into_iter().map(Ok).collect(),
+                    Err(error) => vec![Err(error)],
+                };
+                stream::iter(groups)
+            })
+            .flatten()
+    }
+
+    /// Execute a browse query and stream every torrent of every matching group across all pages.
+    ///
+    /// Flattened variant of [`browse_all`](Self::browse_all).
+    pub fn browse_all_torrents<'a>(
+        &'a self,
+        request: &BrowseRequest,
+        max_pages: Option<u32>,
+    ) -> impl Stream<Item = Result<BrowseTorrent, GazelleError>> + use<'a> {
+        self.browse_all(request, max_pages)
+            .map(|group| {
+                let torrents: Vec<_> = match group {
+                    Ok(group) => group.torrents.into_iter().map(Ok).collect(),
+                    Err(error) => vec![Err(error)],
+                };
+                stream::iter(torrents)
+            })
+            .flatten()
+    }
+
+    fn browse_pages<'a>(
+        &'a self,
+        request: &BrowseRequest,
+        max_pages: Option<u32>,
+    ) -> impl Stream<Item = Result<BrowseResponse, GazelleError>> + use<'a> {
+        let state = BrowsePageState {
+            request: request.clone(),
+            fetched: 0,
+        };
+        stream::unfold(Some(state), move |state| async move {
+            let mut state = state?;
+            if max_pages.is_some_and(|max_pages| state.fetched >= max_pages) {
+                return None;
+            }
+            let current = state.request.page.unwrap_or(1);
+            let response = self
+                .get_with_priority(state.request.to_query(), RequestPriority::Background)
+                .await;
+            let response = match response {
+                Ok(response) => response,
+                Err(error) => return Some((Err(error), None)),
+            };
+            state.fetched += 1;
+            let next = next_browse_page(current, &response, state.fetched, max_pages);
+            let state = next.map(|page| {
+                state.request.page = Some(page);
+                state
+            });
+            Some((Ok(response), state))
+        })
+    }
+}
+
+struct BrowsePageState {
+    request: BrowseRequest,
+    fetched: u32,
+}
+
+/// Return the page to fetch after `current`, or `None` if the browse is complete.
+///
+/// - `None` when the response has no results or no page count
+/// - `None` when `current` is the last page
+/// - `None` when `fetched` has reached `max_pages`
+fn next_browse_page(
+    current: u32,
+    response: &BrowseResponse,
+    fetched: u32,
+    max_pages: Option<u32>,
+) -> Option<u32> {
+    if response.results.is_empty() {
+        return None;
+    }
+    let pages = response.pages?;
+    if current >= pages {
+        return None;
+    }
+    if max_pages.is_some_and(|max_pages| fetched >= max_pages) {
+        return None;
+    }
+    current.checked_add(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+    use serial_test::serial;
+
+    fn response(pages: Option<u32>, results: usize) -> BrowseResponse {
+        BrowseResponse {
+            current_page: pages.map(|_| 1),
+            pages,
+            results: vec![BrowseGroup::default(); results],
+        }
+    }
+
+    #[test]
+    fn next_browse_page_advances() {
+        let output = next_browse_page(1, &response(Some(3), 1), 1, None);
+        assert_eq!(output, Some(2));
+    }
+
+    #[test]
+    fn next_browse_page_stops_at_last_page() {
+        let output = next_browse_page(3, &response(Some(3), 1), 3, None);
+        assert_eq!(output, None);
+    }
+
+    #[test]
+    fn next_browse_page_stops_at_max_pages() {
+        let output = next_browse_page(5, &response(Some(10), 1), 2, Some(2));
+        assert_eq!(output, None);
+    }
+
+    #[test]
+    fn next_browse_page_below_max_pages() {
+        let output = next_browse_page(5, &response(Some(10), 1), 1, Some(2));
+        assert_eq!(output, Some(6));
+    }
+
+    #[test]
+    fn next_browse_page_stops_when_empty() {
+        let output = next_browse_page(1, &response(None, 0), 1, None);
+        assert_eq!(output, None);
+    }
+
+    #[test]
+    fn next_browse_page_stops_without_page_count() {
+        let output = next_browse_page(1, &response(None, 1), 1, None);
+        assert_eq!(output, None);
+    }
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn browse_all() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, _examples| async move {
+            let request = BrowseRequest {
+                format: Some(Format::FLAC),
+                category: Some(Category::Music),
+                ..BrowseRequest::default()
+            };
+            let client = client.lock().await;
+            let first = client.browse(&request).await?;
+            let groups: Vec<BrowseGroup> =
+                client.browse_all(&request, Some(2)).try_collect().await?;
+            assert!(
+                groups.len() > first.results.len(),
+                "[{name}] expected more than one page of results"
+            );
+            assert_eq!(
+                groups.first().map(|group| group.group_id),
+                first.results.first().map(|group| group.group_id),
+                "[{name}] expected the first page first"
+            );
+            Ok(())
+        })
+        .await
+    }
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn browse_all_torrents() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, _examples| async move {
+            let request = BrowseRequest {
+                format: Some(Format::FLAC),
+                category: Some(Category::Music),
+                ..BrowseRequest::default()
+            };
+            let client = client.lock().await;
+            let torrents: Vec<BrowseTorrent> = client
+                .browse_all_torrents(&request, Some(1))
+                .try_collect()
+                .await?;
+            assert!(!torrents.is_empty(), "[{name}] expected torrents");
+            Ok(())
+        })
+        .await
+    }
+}
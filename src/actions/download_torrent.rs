@@ -1,37 +1,194 @@
 use crate::prelude::*;
-use reqwest::Response;
+use futures::StreamExt;
 use reqwest::header::CONTENT_TYPE;
+use reqwest::{Method, Response};
+use std::ffi::OsString;
+use std::io::ErrorKind;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::fs::{File, remove_file, rename};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Counter making each temporary path of this process unique.
+static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
 impl GazelleClient {
     /// Get the content of the .torrent file as a buffer
     ///
+    /// - Retried according to the retry policy
+    ///
     /// # See Also
     /// - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#download>
     pub async fn download_torrent(&self, id: u32) -> Result<Vec<u8>, GazelleError> {
-        let query = format!("action=download&id={id}");
-        let result = self.get_internal(&query).await;
-        let response = result.map_err(GazelleError::request)?;
-        let status_code = response.status();
-        let content_type = get_content_type(&response).unwrap_or_default();
-        if !content_type.contains("application/x-bittorrent") {
-            let json = response.text().await.map_err(GazelleError::response)?;
-            let response = deserialize::<JsonValue>(json)?;
-            return get_result(status_code, response).map(|_| Vec::new());
-        }
-        if status_code.is_success() {
-            let bytes = response
-                .bytes()
-                .await
-                .expect("Response should not be empty");
-            let buffer = bytes.to_vec();
-            Ok(buffer)
-        } else {
-            Err(GazelleError::match_status_error(status_code, None)
-                .unwrap_or_else(|| GazelleError::other(String::new(), status_code.as_u16())))
-        }
+        self.instrument_action("download_torrent", async {
+            self.with_retry(|_| async {
+                let response = self.download_response(id).await?;
+                let bytes = response.bytes().await.map_err(GazelleError::response)?;
+                Ok(bytes.to_vec())
+            })
+            .await
+        })
+        .await
+    }
+
+    /// Stream the content of the .torrent file to a writer.
+    ///
+    /// - Returns the number of bytes written
+    /// - The writer is flushed but not closed
+    /// - The request is retried according to the retry policy, but a failure while
+    ///   streaming is not as the writer may already be partially written
+    ///
+    /// # See Also
+    /// - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#download>
+    pub async fn download_torrent_to<W: AsyncWrite + Unpin>(
+        &self,
+        id: u32,
+        writer: &mut W,
+    ) -> Result<u64, GazelleError> {
+        self.instrument_action("download_torrent_to", async {
+            let response = self.with_retry(|_| self.download_response(id)).await?;
+            let written = write_response(response, writer).await?;
+            trace!("Downloaded {written} bytes of torrent {id}");
+            Ok(written)
+        })
+        .await
+    }
+
+    /// Save the .torrent file to a path.
+    ///
+    /// - Returns the number of bytes written
+    /// - Written to a temporary file in the same directory then renamed so `path` is
+    ///   never left partially written
+    /// - An existing file at `path` is replaced
+    /// - Retried according to the retry policy
+    ///
+    /// # See Also
+    /// - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#download>
+    pub async fn download_torrent_to_path(
+        &self,
+        id: u32,
+        path: &Path,
+    ) -> Result<u64, GazelleError> {
+        self.instrument_action("download_torrent_to_path", async {
+            let temp_path = get_temp_path(path).map_err(GazelleError::write_file)?;
+            let result = self
+                .with_retry(|_| self.download_torrent_to_temp(id, &temp_path))
+                .await;
+            let result = match result {
+                Ok(written) => rename(&temp_path, path)
+                    .await
+                    .map(|()| written)
+                    .map_err(GazelleError::write_file),
+                Err(error) => Err(error),
+            };
+            if result.is_err() {
+                let _ = remove_file(&temp_path).await;
+            }
+            result
+        })
+        .await
+    }
+
+    /// Download to the temporary path, truncating anything left by a previous attempt.
+    async fn download_torrent_to_temp(
+        &self,
+        id: u32,
+        temp_path: &Path,
+    ) -> Result<u64, GazelleError> {
+        let mut file = File::create(temp_path)
+            .await
+            .map_err(GazelleError::write_file)?;
+        let response = self.download_response(id).await?;
+        let written = write_response(response, &mut file).await?;
+        file.sync_all().await.map_err(GazelleError::write_file)?;
+        trace!("Downloaded {written} bytes of torrent {id}");
+        Ok(written)
+    }
+
+    /// Request the .torrent file and return the response if the body is the torrent.
+    async fn download_response(&self, id: u32) -> Result<Response, GazelleError> {
+        let mut request = MiddlewareRequest::new(Method::GET, format!("action=download&id={id}"));
+        let result = self.get_internal(&mut request, self.priority).await;
+        let (status, body_size) = match &result {
+            Ok(response) => (Some(response.status()), response.content_length()),
+            Err(_) => (None, None),
+        };
+        let result = match result {
+            Ok(response) => {
+                let retry_after = get_retry_after(&response);
+                check_download_response(response)
+                    .await
+                    .map_err(|error| error.with_retry_after(retry_after))
+            }
+            Err(error) => Err(error),
+        };
+        self.after_response(&request, status, body_size, result.as_ref().err());
+        self.record_rate_feedback(&result);
+        result
     }
 }
 
+/// Return the response if the body is the torrent, otherwise the error it contains.
+///
+/// - A JSON body without an error is an [`ApiResponseKind::Other`] error
+async fn check_download_response(response: Response) -> Result<Response, GazelleError> {
+    let status_code = response.status();
+    let content_type = get_content_type(&response).unwrap_or_default();
+    if !content_type.contains("application/x-bittorrent") {
+        let json = response.text().await.map_err(GazelleError::response)?;
+        let response = deserialize_with_status::<JsonValue>(status_code, json)?;
+        get_result(Some("download"), status_code, response)?;
+        return Err(GazelleError::other(
+            "response is not a torrent file".to_owned(),
+            status_code.as_u16(),
+        ));
+    }
+    if status_code.is_success() {
+        Ok(response)
+    } else {
+        Err(GazelleError::match_status_error(status_code, None)
+            .unwrap_or_else(|| GazelleError::other(String::new(), status_code.as_u16())))
+    }
+}
+
+/// Stream the response body to a writer and flush it.
+async fn write_response<W: AsyncWrite + Unpin>(
+    response: Response,
+    writer: &mut W,
+) -> Result<u64, GazelleError> {
+    let mut stream = response.bytes_stream();
+    let mut written: u64 = 0;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(GazelleError::response)?;
+        writer
+            .write_all(&chunk)
+            .await
+            .map_err(GazelleError::write_file)?;
+        written += u64::try_from(chunk.len()).expect("chunk length should fit in u64");
+    }
+    writer.flush().await.map_err(GazelleError::write_file)?;
+    Ok(written)
+}
+
+/// Get a unique hidden temporary path in the same directory as `path`.
+///
+/// - The same directory is used so the final rename stays on one filesystem
+/// - The process id and a counter are appended so concurrent downloads to the same
+///   path never share a temporary file
+fn get_temp_path(path: &Path) -> Result<PathBuf, IoError> {
+    let file_name = path.file_name().ok_or_else(|| {
+        IoError::new(
+            ErrorKind::InvalidInput,
+            format!("path has no file name: {}", path.display()),
+        )
+    })?;
+    let mut temp_name = OsString::from(".");
+    temp_name.push(file_name);
+    let count = TEMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+    temp_name.push(format!(".{}.{count}.part", process::id()));
+    Ok(path.with_file_name(temp_name))
+}
+
 fn get_content_type(response: &Response) -> Option<String> {
     let content_type = response
         .headers()
@@ -44,8 +201,54 @@ fn get_content_type(response: &Response) -> Option<String> {
 
 #[cfg(test)]
 mod tests {
-    use crate::prelude::*;
+    use super::*;
     use serial_test::serial;
+    use std::env::temp_dir;
+    use std::fs::{metadata, read_dir, remove_file as remove_file_sync};
+
+    /// Whether a temporary file for `path` is left in its directory.
+    fn has_temp_file(path: &Path) -> bool {
+        let prefix = format!(".{}.", path.file_name().unwrap_or_default().display());
+        let directory = path.parent().unwrap_or(Path::new("."));
+        read_dir(directory)
+            .expect("should read directory")
+            .filter_map(Result::ok)
+            .any(|entry| {
+                let path = entry.path();
+                let name = entry.file_name().to_string_lossy().to_string();
+                name.starts_with(&prefix) && path.extension().is_some_and(|ext| ext == "part")
+            })
+    }
+
+    #[test]
+    fn get_temp_path_hidden_sibling() {
+        let output = get_temp_path(Path::new("/tmp/torrents/123.torrent")).expect("should be ok");
+        assert_eq!(output.parent(), Some(Path::new("/tmp/torrents")));
+        let name = output.file_name().unwrap_or_default().to_string_lossy();
+        let prefix = format!(".123.torrent.{}.", process::id());
+        assert!(name.starts_with(&prefix), "unexpected name: {name}");
+        assert_eq!(output.extension().unwrap_or_default(), "part");
+    }
+
+    #[test]
+    fn get_temp_path_relative() {
+        let output = get_temp_path(Path::new("123.torrent")).expect("should be ok");
+        assert_eq!(output.parent(), Some(Path::new("")));
+    }
+
+    #[test]
+    fn get_temp_path_unique() {
+        let path = Path::new("/tmp/torrents/123.torrent");
+        let first = get_temp_path(path).expect("should be ok");
+        let second = get_temp_path(path).expect("should be ok");
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn get_temp_path_without_file_name() {
+        let error = get_temp_path(Path::new("/")).expect_err("should be an error");
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
 
     #[tokio::test]
     #[serial]
@@ -86,4 +289,94 @@ mod tests {
         })
         .await
     }
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn download_torrent_verify_metainfo() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, examples| async move {
+            let client = client.lock().await;
+            let response = client.get_torrent(examples.torrent).await?;
+            let bytes = client.download_torrent(examples.torrent).await?;
+            let mismatches =
+                verify_metainfo(&response.torrent, &bytes).expect("metainfo should parse");
+            assert!(
+                mismatches.is_empty(),
+                "[{name}] metainfo should match torrent: {mismatches:?}"
+            );
+            Ok(())
+        })
+        .await
+    }
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn download_torrent_to() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, examples| async move {
+            let client = client.lock().await;
+            let expected = client.download_torrent(examples.torrent).await?;
+            let mut buffer = Vec::new();
+            let written = client
+                .download_torrent_to(examples.torrent, &mut buffer)
+                .await?;
+            assert_eq!(
+                written,
+                u64::try_from(buffer.len()).expect("length should fit in u64"),
+                "[{name}] byte count should match"
+            );
+            assert_eq!(buffer, expected, "[{name}] content should match");
+            Ok(())
+        })
+        .await
+    }
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn download_torrent_to_path() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, examples| async move {
+            let path = temp_dir().join(format!("gazelle_api_{name}_download.torrent"));
+            let written = client
+                .lock()
+                .await
+                .download_torrent_to_path(examples.torrent, &path)
+                .await?;
+            let metadata = metadata(&path).expect("file should exist");
+            assert_eq!(metadata.len(), written, "[{name}] byte count should match");
+            assert!(
+                !has_temp_file(&path),
+                "[{name}] temp file should be renamed"
+            );
+            remove_file_sync(&path).expect("should remove file");
+            Ok(())
+        })
+        .await
+    }
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn download_torrent_to_path_invalid() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, _examples| async move {
+            let path = temp_dir().join(format!("gazelle_api_{name}_invalid.torrent"));
+            let error = client
+                .lock()
+                .await
+                .download_torrent_to_path(u32::MAX, &path)
+                .await
+                .expect_err("should be an error");
+            assert!(
+                error.is_missing(),
+                "[{name}] expected missing, got {error:?}"
+            );
+            assert!(!path.exists(), "[{name}] file should not be created");
+            assert!(
+                !has_temp_file(&path),
+                "[{name}] temp file should be removed"
+            );
+            Ok(())
+        })
+        .await
+    }
 }
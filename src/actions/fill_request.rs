@@ -0,0 +1,56 @@
+use crate::prelude::*;
+use reqwest::multipart::Form;
+
+impl GazelleClient {
+    /// Fill a request with an existing torrent.
+    ///
+    /// - Returns [`ApiResponseKind::RequestAlreadyFilled`] if the request has already been filled
+    /// - Returns [`ApiResponseKind::RequestMismatch`] if the torrent does not satisfy the request
+    ///
+    /// # See Also
+    /// - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#request-fill>
+    pub async fn fill_request(
+        &self,
+        request_id: u32,
+        torrent_id: u32,
+    ) -> Result<FillRequestResponse, GazelleError> {
+        self.instrument_action("fill_request", async {
+            let form = Form::new()
+                .text("requestid", request_id.to_string())
+                .text("torrentid", torrent_id.to_string());
+            self.post("requestfill", form).await
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serial_test::serial;
+
+    use crate::prelude::*;
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn fill_request_invalid() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, examples| async move {
+            // Act
+            let error = client
+                .lock()
+                .await
+                .fill_request(u32::MAX, examples.torrent)
+                .await
+                .expect_err("should be an error");
+            println!("[{name}] {error:?}");
+
+            // Assert
+            assert!(
+                matches!(error.operation, GazelleOperation::ApiResponse(_)),
+                "[{name}] unexpected error: {error:?}"
+            );
+            Ok(())
+        })
+        .await
+    }
+}
@@ -0,0 +1,74 @@
+use crate::prelude::*;
+
+impl GazelleClient {
+    /// Get an artist by id or name.
+    ///
+    /// Includes the artist's tags, similar artists, statistics and every
+    /// torrent group they are credited on.
+    ///
+    /// # See Also
+    /// - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#artist>
+    pub async fn get_artist(&self, artist: ArtistLookup) -> Result<ArtistResponse, GazelleError> {
+        self.instrument_action("get_artist", self.get(artist.to_query()))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use serial_test::serial;
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_artist() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, examples| async move {
+            let client = client.lock().await;
+            let group = client.get_torrent_group(examples.group).await?;
+            let credit = group
+                .group
+                .music_info
+                .and_then(|info| info.artists.into_iter().next())
+                .expect("example group should have an artist");
+            let by_id = client.get_artist(ArtistLookup::Id(credit.id)).await?;
+            assert_eq!(by_id.id, credit.id, "[{name}] artist id mismatch");
+            assert!(
+                by_id
+                    .torrent_groups
+                    .iter()
+                    .any(|group| group.group_id == examples.group),
+                "[{name}] artist should include the example group"
+            );
+            let by_name = client
+                .get_artist(ArtistLookup::Name(credit.name.clone()))
+                .await?;
+            assert_eq!(
+                by_name.id, credit.id,
+                "[{name}] artist name lookup mismatch"
+            );
+            Ok(())
+        })
+        .await
+    }
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_artist_invalid() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, _examples| async move {
+            let error = client
+                .lock()
+                .await
+                .get_artist(ArtistLookup::Id(u32::MAX))
+                .await
+                .expect_err("should be an error");
+            assert!(
+                error.is_missing(),
+                "[{name}] expected missing, got {error:?}"
+            );
+            Ok(())
+        })
+        .await
+    }
+}
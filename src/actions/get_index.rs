@@ -0,0 +1,39 @@
+use crate::prelude::*;
+
+impl GazelleClient {
+    /// Get the account that owns the API key.
+    ///
+    /// Includes the user id, authkey, passkey, notification counts and statistics.
+    ///
+    /// # See Also
+    /// - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#index>
+    pub async fn get_index(&self) -> Result<IndexResponse, GazelleError> {
+        self.instrument_action("get_index", self.get("action=index".to_owned()))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use serial_test::serial;
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_index() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, _examples| async move {
+            let response = client.lock().await.get_index().await?;
+            assert!(
+                !response.username.is_empty(),
+                "[{name}] username should not be empty"
+            );
+            assert!(
+                !response.passkey.is_empty(),
+                "[{name}] passkey should not be empty"
+            );
+            Ok(())
+        })
+        .await
+    }
+}
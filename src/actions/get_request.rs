@@ -0,0 +1,60 @@
+use crate::prelude::*;
+
+impl GazelleClient {
+    /// Get a request by id.
+    ///
+    /// # See Also
+    /// - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#request>
+    pub async fn get_request(&self, id: u32) -> Result<RequestDetail, GazelleError> {
+        self.instrument_action("get_request", self.get(format!("action=request&id={id}")))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use serial_test::serial;
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_request() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, _examples| async move {
+            let client = client.lock().await;
+            let search = client.search_requests(&RequestSearch::default()).await?;
+            let summary = search
+                .results
+                .first()
+                .expect("should have at least one request");
+            let detail = client.get_request(summary.request_id).await?;
+            assert_eq!(
+                detail.request_id, summary.request_id,
+                "[{name}] request id mismatch"
+            );
+            assert_eq!(detail.title, summary.title, "[{name}] title mismatch");
+            Ok(())
+        })
+        .await
+    }
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_request_invalid() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, _examples| async move {
+            let error = client
+                .lock()
+                .await
+                .get_request(u32::MAX)
+                .await
+                .expect_err("should be an error");
+            assert!(
+                error.is_missing(),
+                "[{name}] expected missing, got {error:?}"
+            );
+            Ok(())
+        })
+        .await
+    }
+}
@@ -8,7 +8,8 @@ impl GazelleClient {
     /// # See Also
     /// - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#torrent>
     pub async fn get_torrent(&self, id: u32) -> Result<TorrentResponse, GazelleError> {
-        self.get(format!("action=torrent&id={id}")).await
+        self.instrument_action("get_torrent", self.get(format!("action=torrent&id={id}")))
+            .await
     }
 
     /// Get a torrent by its info hash
@@ -19,8 +20,11 @@ impl GazelleClient {
     /// # See Also
     /// - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#torrent>
     pub async fn get_torrent_by_hash(&self, hash: &str) -> Result<TorrentResponse, GazelleError> {
-        self.get(format!("action=torrent&hash={}", hash.to_uppercase()))
-            .await
+        self.instrument_action(
+            "get_torrent_by_hash",
+            self.get(format!("action=torrent&hash={}", hash.to_uppercase())),
+        )
+        .await
     }
 }
 
@@ -6,7 +6,8 @@ impl GazelleClient {
     /// # See Also
     /// - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#user>
     pub async fn get_user(&self, id: u32) -> Result<User, GazelleError> {
-        self.get(format!("action=user&id={id}")).await
+        self.instrument_action("get_user", self.get(format!("action=user&id={id}")))
+            .await
     }
 }
 
@@ -0,0 +1,147 @@
+use crate::prelude::*;
+
+/// Number of entries requested per page by [`GazelleClient::get_all_user_torrents`].
+const USER_TORRENTS_PAGE_SIZE: u32 = 500;
+
+impl GazelleClient {
+    /// Get a page of a user's seeding, leeching, uploaded or snatched torrents.
+    ///
+    /// - `limit` is the maximum number of entries to return
+    /// - `offset` is the number of entries to skip
+    /// - Subject to the user's paranoia settings
+    ///
+    /// # See Also
+    /// - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#user-torrents>
+    pub async fn get_user_torrents(
+        &self,
+        user_id: u32,
+        kind: UserTorrentKind,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<UserTorrent>, GazelleError> {
+        self.instrument_action(
+            "get_user_torrents",
+            self.get_user_torrents_with_priority(user_id, kind, limit, offset, self.priority),
+        )
+        .await
+    }
+
+    /// Get every one of a user's seeding, leeching, uploaded or snatched torrents.
+    ///
+    /// Requests pages of 500 entries until a short page is returned.
+    /// Each page is subject to the rate limiter.
+    pub async fn get_all_user_torrents(
+        &self,
+        user_id: u32,
+        kind: UserTorrentKind,
+    ) -> Result<Vec<UserTorrent>, GazelleError> {
+        self.instrument_action("get_all_user_torrents", async {
+            let mut entries = Vec::new();
+            let mut offset = Some(0);
+            while let Some(current) = offset {
+                let page = self
+                    .get_user_torrents_with_priority(
+                        user_id,
+                        kind,
+                        USER_TORRENTS_PAGE_SIZE,
+                        current,
+                        RequestPriority::Background,
+                    )
+                    .await?;
+                offset = next_offset(current, USER_TORRENTS_PAGE_SIZE, page.len());
+                entries.extend(page);
+            }
+            Ok(entries)
+        })
+        .await
+    }
+
+    async fn get_user_torrents_with_priority(
+        &self,
+        user_id: u32,
+        kind: UserTorrentKind,
+        limit: u32,
+        offset: u32,
+        priority: RequestPriority,
+    ) -> Result<Vec<UserTorrent>, GazelleError> {
+        let query = format!(
+            "action=user_torrents&id={user_id}&type={}&limit={limit}&offset={offset}",
+            kind.as_query()
+        );
+        let response: UserTorrentsResponse = self.get_with_priority(query, priority).await?;
+        Ok(response.into_entries(kind))
+    }
+}
+
+/// Return the offset of the next page, or `None` if `received` indicates the last page.
+fn next_offset(offset: u32, limit: u32, received: usize) -> Option<u32> {
+    let received = u32::try_from(received).ok()?;
+    if received < limit {
+        return None;
+    }
+    offset.checked_add(received)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[test]
+    fn next_offset_full_page() {
+        assert_eq!(next_offset(0, 500, 500), Some(500));
+        assert_eq!(next_offset(500, 500, 500), Some(1000));
+    }
+
+    #[test]
+    fn next_offset_short_page() {
+        assert_eq!(next_offset(500, 500, 499), None);
+    }
+
+    #[test]
+    fn next_offset_empty_page() {
+        assert_eq!(next_offset(0, 500, 0), None);
+    }
+
+    #[test]
+    fn next_offset_overflow() {
+        assert_eq!(next_offset(u32::MAX, 500, 500), None);
+    }
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_user_torrents() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, examples| async move {
+            let response = client
+                .lock()
+                .await
+                .get_user_torrents(examples.user, UserTorrentKind::Uploaded, 10, 0)
+                .await?;
+            assert!(response.len() <= 10, "[{name}] expected at most 10 entries");
+            Ok(())
+        })
+        .await
+    }
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_all_user_torrents() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, examples| async move {
+            let client = client.lock().await;
+            let first = client
+                .get_user_torrents(examples.user, UserTorrentKind::Uploaded, 10, 0)
+                .await?;
+            let all = client
+                .get_all_user_torrents(examples.user, UserTorrentKind::Uploaded)
+                .await?;
+            assert!(
+                all.len() >= first.len(),
+                "[{name}] expected all pages to include the first page"
+            );
+            Ok(())
+        })
+        .await
+    }
+}
@@ -1,6 +1,13 @@
 mod browse;
+mod browse_all;
 mod download_torrent;
+mod fill_request;
+mod get_artist;
+mod get_index;
+mod get_request;
 mod get_torrent;
 mod get_torrent_group;
 mod get_user;
+mod get_user_torrents;
+mod search_requests;
 mod upload_torrent;
@@ -0,0 +1,42 @@
+use crate::prelude::*;
+
+impl GazelleClient {
+    /// Search requests.
+    ///
+    /// # See Also
+    /// - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#request-search>
+    pub async fn search_requests(
+        &self,
+        search: &RequestSearch,
+    ) -> Result<RequestSearchResponse, GazelleError> {
+        self.instrument_action("search_requests", self.get(search.to_query()))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use serial_test::serial;
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn search_requests() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, _examples| async move {
+            let search = RequestSearch {
+                category: Some(Category::Music),
+                page: Some(1),
+                ..RequestSearch::default()
+            };
+            let response = client.lock().await.search_requests(&search).await?;
+            assert_eq!(response.current_page, 1, "[{name}] expected first page");
+            assert!(
+                !response.results.is_empty(),
+                "[{name}] expected at least one request"
+            );
+            Ok(())
+        })
+        .await
+    }
+}
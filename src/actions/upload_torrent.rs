@@ -1,33 +1,115 @@
 use crate::prelude::*;
+use std::io::ErrorKind;
+use tokio::fs::read;
 
 impl GazelleClient {
     /// Upload a torrent
     ///
+    /// - Only retried if `retry_uploads` is set
+    /// - Before each retry the info hash is looked up and, if the failed attempt was
+    ///   accepted, the existing torrent is returned instead of uploading again
+    ///
     /// # See Also
     ///  - <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#upload>
     pub async fn upload_torrent(&self, upload: UploadForm) -> Result<UploadResponse, GazelleError> {
-        let form = upload.to_form().map_err(GazelleError::upload)?;
-        self.limiter.execute().await;
-        let path = "/ajax.php?action=upload";
-        trace!("Sending request POST {path}");
-        let url = format!("{}{path}", self.base_url);
-        let start = SystemTime::now();
-        let result = self.client.post(&url).multipart(form).send().await;
-        let elapsed = start
-            .elapsed()
-            .expect("elapsed should not fail")
-            .as_secs_f64();
-        trace!("Received response after {elapsed:.3}");
-        handle_result(result).await
+        self.instrument_action("upload_torrent", async {
+            if !self.retry_uploads {
+                let form = upload.to_form().map_err(GazelleError::upload)?;
+                return self.post("upload", form).await;
+            }
+            let hash = get_info_hash(&upload.path).await?;
+            self.with_retry(|attempt| {
+                let upload = upload.clone();
+                let hash = &hash;
+                async move {
+                    if attempt > 0
+                        && let Some(response) = self.find_upload(hash).await?
+                    {
+                        return Ok(response);
+                    }
+                    let form = upload.to_form().map_err(GazelleError::upload)?;
+                    self.post("upload", form).await
+                }
+            })
+            .await
+        })
+        .await
+    }
+
+    /// Find a torrent that was uploaded by a previous attempt.
+    async fn find_upload(&self, hash: &str) -> Result<Option<UploadResponse>, GazelleError> {
+        match self.get_torrent_by_hash(hash).await {
+            Ok(response) => {
+                warn!(
+                    "Upload was accepted despite failing, found torrent {}",
+                    response.torrent.id
+                );
+                Ok(Some(UploadResponse {
+                    private: false,
+                    source: false,
+                    request_id: None,
+                    torrent_id: response.torrent.id,
+                    group_id: response.group.id,
+                }))
+            }
+            Err(error) if error.is_missing() => Ok(None),
+            Err(error) => Err(error),
+        }
     }
 }
 
+/// Read the info hash of the torrent file to upload.
+async fn get_info_hash(path: &Path) -> Result<String, GazelleError> {
+    let bytes = read(path).await.map_err(GazelleError::upload)?;
+    let torrent = TorrentFile::from_bytes(&bytes)
+        .map_err(|error| GazelleError::upload(IoError::new(ErrorKind::InvalidData, error)))?;
+    Ok(torrent.info_hash_hex())
+}
+
 #[cfg(test)]
 mod tests {
     use serial_test::serial;
+    use std::env::temp_dir;
+    use std::fs::{remove_file, write};
 
+    use super::get_info_hash;
     use crate::prelude::*;
 
+    #[tokio::test]
+    async fn get_info_hash_from_file() {
+        // Arrange
+        let path = temp_dir().join("gazelle_api_get_info_hash.torrent");
+        let mut bytes =
+            b"d4:infod6:lengthi12e4:name5:a.txt12:piece lengthi16384e6:pieces20:".to_vec();
+        bytes.extend_from_slice(&[0; 20]);
+        bytes.extend_from_slice(b"7:privatei1e6:source3:OPSee");
+        write(&path, bytes).expect("should write file");
+
+        // Act
+        let hash = get_info_hash(&path).await;
+        remove_file(&path).expect("should remove file");
+
+        // Assert
+        assert_eq!(
+            hash.expect("should read hash"),
+            "150fdeb252060b499d59c8728e2ed4b4a776c109"
+        );
+    }
+
+    #[tokio::test]
+    async fn get_info_hash_invalid_file() {
+        // Arrange
+        let path = temp_dir().join("gazelle_api_get_info_hash_invalid.torrent");
+        write(&path, b"not a torrent").expect("should write file");
+
+        // Act
+        let error = get_info_hash(&path).await.expect_err("should be an error");
+        remove_file(&path).expect("should remove file");
+
+        // Assert
+        assert_eq!(error.operation, GazelleOperation::ReadFile);
+    }
+
     #[tokio::test]
     #[serial]
     #[ignore = "integration test requiring API credentials"]
@@ -35,7 +117,7 @@ mod tests {
         for_each_indexer(|name, client, examples| async move {
             // Arrange
             let form = UploadForm {
-                path: PathBuf::from("/srv/shared/tests/example-1.torrent"),
+                path: PathBuf::from("src/tests/fixtures/upload.torrent"),
                 category_id: Category::Music,
                 remaster_year: 0,
                 remaster_title: "ALBUM TITLE".to_owned(),
@@ -0,0 +1,38 @@
+use crate::prelude::*;
+
+/// Bounds for adapting the rate of a [`RateLimiter`] to `429 Too Many Requests` responses.
+///
+/// - Each `429` halves the number of requests allowed per duration, at most once per
+///   duration so a burst of rejected requests counts once, down to `min_num`
+/// - Every `recover_after` successful responses in a row allow one more request per
+///   duration, up to the configured rate
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct AdaptiveRate {
+    /// Minimum number of requests allowed per duration.
+    ///
+    /// Default: `1`
+    #[serde(default = "default_min_num")]
+    pub min_num: usize,
+    /// Number of successful responses in a row before the rate is increased.
+    ///
+    /// Default: `20`
+    #[serde(default = "default_recover_after")]
+    pub recover_after: usize,
+}
+
+impl Default for AdaptiveRate {
+    fn default() -> Self {
+        Self {
+            min_num: default_min_num(),
+            recover_after: default_recover_after(),
+        }
+    }
+}
+
+fn default_min_num() -> usize {
+    1
+}
+
+fn default_recover_after() -> usize {
+    20
+}
@@ -0,0 +1,306 @@
+use crate::prelude::*;
+use http::Response as HttpResponse;
+use reqwest::Response;
+use reqwest::header::{CONTENT_TYPE, HeaderName, RETRY_AFTER};
+use serde_json::to_string_pretty as json_to_string_pretty;
+use sha1::{Digest, Sha1};
+use std::fmt::Write as FmtWrite;
+use std::io::ErrorKind;
+use std::str::from_utf8;
+use tokio::fs::{create_dir_all, read, read_to_string, write};
+
+/// Records the responses of a [`GazelleClient`] to a directory, or replays them
+/// without sending any request.
+///
+/// Each request is stored as a JSON file named by its action and a hash of its
+/// method and query.
+///
+/// - Bodies are kept as sent by the indexer so they can be copied into `src/tests/fixtures`
+/// - Request headers, including the API key, are not recorded
+/// - `passkey` and `authkey` fields, and the announce URLs of `.torrent` files, are redacted
+/// - `.torrent` files are chosen by their `application/x-bittorrent` content type and
+///   written next to the JSON file, as are other bodies that aren't UTF-8
+/// - Repeating a request overwrites the earlier recording
+/// - Form bodies aren't part of the name, so every `upload` replays the same response
+///
+/// Replay skips the rate limiter and fails with [`GazelleOperation::SendRequest`]
+/// if the request wasn't recorded.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Cassette {
+    /// Whether to record or replay
+    pub mode: CassetteMode,
+    /// Directory of the recordings
+    pub path: PathBuf,
+}
+
+impl Cassette {
+    /// Create a [`Cassette`] that records to a directory
+    #[must_use]
+    pub fn record(path: PathBuf) -> Self {
+        Self {
+            mode: CassetteMode::Record,
+            path,
+        }
+    }
+
+    /// Create a [`Cassette`] that replays from a directory
+    #[must_use]
+    pub fn replay(path: PathBuf) -> Self {
+        Self {
+            mode: CassetteMode::Replay,
+            path,
+        }
+    }
+
+    /// Write the response to the cassette.
+    ///
+    /// Returns an unread copy of the response with the body before redaction.
+    pub(crate) async fn record_response(
+        &self,
+        method: &str,
+        query: &str,
+        response: Response,
+    ) -> Result<Response, GazelleError> {
+        let mut entry = CassetteEntry {
+            method: method.to_owned(),
+            query: query.to_owned(),
+            status: response.status().as_u16(),
+            content_type: get_header(&response, &CONTENT_TYPE),
+            retry_after: get_header(&response, &RETRY_AFTER),
+            body: None,
+            body_file: None,
+        };
+        let body = response
+            .bytes()
+            .await
+            .map_err(GazelleError::response)?
+            .to_vec();
+        let stem = get_stem(method, query);
+        create_dir_all(&self.path)
+            .await
+            .map_err(GazelleError::cassette)?;
+        let is_torrent = entry
+            .content_type
+            .as_deref()
+            .is_some_and(|content_type| content_type.contains("application/x-bittorrent"));
+        match from_utf8(&body) {
+            Ok(text) if !is_torrent => entry.body = Some(redact_json(text)),
+            _ => {
+                let name = format!("{stem}.bin");
+                write(self.path.join(&name), redact_torrent(&body))
+                    .await
+                    .map_err(GazelleError::cassette)?;
+                entry.body_file = Some(name);
+            }
+        }
+        let json = json_to_string_pretty(&entry).expect("cassette entry should serialize");
+        let path = self.path.join(format!("{stem}.json"));
+        write(&path, json).await.map_err(GazelleError::cassette)?;
+        trace!("Recorded {method} {query} to {}", path.display());
+        create_response(&entry, body)
+    }
+
+    /// Read the recorded response for the request.
+    pub(crate) async fn replay_response(
+        &self,
+        method: &str,
+        query: &str,
+    ) -> Result<Response, GazelleError> {
+        let path = self.path.join(format!("{}.json", get_stem(method, query)));
+        let json = read_to_string(&path).await.map_err(|error| {
+            GazelleError::replay(IoError::new(
+                error.kind(),
+                format!(
+                    "no recording of {method} {query} at {}: {error}",
+                    path.display()
+                ),
+            ))
+        })?;
+        let entry: CassetteEntry = json_from_str(&json).map_err(GazelleError::deserialization)?;
+        let body = match (&entry.body, &entry.body_file) {
+            (Some(body), _) => body.clone().into_bytes(),
+            (None, Some(name)) => read(self.path.join(name))
+                .await
+                .map_err(GazelleError::replay)?,
+            (None, None) => Vec::new(),
+        };
+        trace!("Replayed {method} {query} from {}", path.display());
+        create_response(&entry, body)
+    }
+}
+
+/// File name without extension for a request.
+///
+/// Example: `torrent_8d0c2d6e3f7b1a94`
+fn get_stem(method: &str, query: &str) -> String {
+    let action: String = query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("action="))
+        .unwrap_or("request")
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect();
+    let hash = Sha1::digest(format!("{method} {query}"));
+    hash.iter()
+        .take(8)
+        .fold(format!("{action}_"), |mut output, byte| {
+            let _ = write!(output, "{byte:02x}");
+            output
+        })
+}
+
+fn get_header(response: &Response, name: &HeaderName) -> Option<String> {
+    let value = response.headers().get(name)?.to_str().ok()?;
+    Some(value.to_owned())
+}
+
+fn create_response(entry: &CassetteEntry, body: Vec<u8>) -> Result<Response, GazelleError> {
+    let mut builder = HttpResponse::builder().status(entry.status);
+    if let Some(content_type) = &entry.content_type {
+        builder = builder.header(CONTENT_TYPE, content_type);
+    }
+    if let Some(retry_after) = &entry.retry_after {
+        builder = builder.header(RETRY_AFTER, retry_after);
+    }
+    let response = builder
+        .body(body)
+        .map_err(|error| GazelleError::replay(IoError::new(ErrorKind::InvalidData, error)))?;
+    Ok(Response::from(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env::temp_dir;
+    use std::fs::{read as read_sync, read_dir, remove_dir_all};
+
+    fn get_path(name: &str) -> PathBuf {
+        let path = temp_dir().join(format!("gazelle_api_cassette_{name}"));
+        let _ = remove_dir_all(&path);
+        path
+    }
+
+    #[test]
+    fn get_stem_by_action() {
+        let output = get_stem("GET", "action=torrent&id=1");
+        assert!(output.starts_with("torrent_"));
+        assert_eq!(output.len(), "torrent_".len() + 16);
+        assert_ne!(output, get_stem("GET", "action=torrent&id=2"));
+        assert_ne!(output, get_stem("POST", "action=torrent&id=1"));
+    }
+
+    #[tokio::test]
+    async fn replay_recorded_response() {
+        // Arrange
+        let path = get_path("replay");
+        let body = r#"{"status":"success","response":{"username":"user","passkey":"secret"}}"#;
+        let response = HttpResponse::builder()
+            .status(429)
+            .header(CONTENT_TYPE, "application/json")
+            .header(RETRY_AFTER, "5")
+            .body(body)
+            .expect("should build response");
+        let cassette = Cassette::record(path.clone());
+
+        // Act
+        let recorded = cassette
+            .record_response("GET", "action=index", Response::from(response))
+            .await
+            .expect("should record");
+        let replayed = Cassette::replay(path.clone())
+            .replay_response("GET", "action=index")
+            .await
+            .expect("should replay");
+
+        // Assert
+        assert_eq!(recorded.text().await.expect("should read body"), body);
+        assert_eq!(replayed.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(get_header(&replayed, &RETRY_AFTER).as_deref(), Some("5"));
+        let replayed = replayed.text().await.expect("should read body");
+        assert!(!replayed.contains("secret"));
+        assert!(replayed.contains(r#""username":"user""#));
+        assert_eq!(read_dir(&path).expect("should read dir").count(), 1);
+        remove_dir_all(&path).expect("should remove dir");
+    }
+
+    #[tokio::test]
+    async fn replay_binary_body() {
+        // Arrange
+        let path = get_path("binary");
+        let body = vec![0xff, 0xfe, 0x00];
+        let response = HttpResponse::builder()
+            .status(200)
+            .body(body.clone())
+            .expect("should build response");
+        Cassette::record(path.clone())
+            .record_response("GET", "action=download&id=1", Response::from(response))
+            .await
+            .expect("should record");
+
+        // Act
+        let replayed = Cassette::replay(path.clone())
+            .replay_response("GET", "action=download&id=1")
+            .await
+            .expect("should replay");
+
+        // Assert
+        assert_eq!(
+            replayed.bytes().await.expect("should read body").to_vec(),
+            body
+        );
+        assert_eq!(read_dir(&path).expect("should read dir").count(), 2);
+        remove_dir_all(&path).expect("should remove dir");
+    }
+
+    #[tokio::test]
+    async fn replay_redacts_utf8_torrent() {
+        // Arrange
+        let path = get_path("torrent");
+        let body = "d8:announce39:https://example.com/0123456789/announce4:infod6:lengthi1e4:name1:a12:piece lengthi1e6:pieces0:ee";
+        let response = HttpResponse::builder()
+            .status(200)
+            .header(CONTENT_TYPE, "application/x-bittorrent; charset=utf-8")
+            .body(body)
+            .expect("should build response");
+        let cassette = Cassette::record(path.clone());
+
+        // Act
+        let recorded = cassette
+            .record_response("GET", "action=download&id=1", Response::from(response))
+            .await
+            .expect("should record");
+        let replayed = Cassette::replay(path.clone())
+            .replay_response("GET", "action=download&id=1")
+            .await
+            .expect("should replay");
+
+        // Assert
+        assert_eq!(recorded.text().await.expect("should read body"), body);
+        let replayed = replayed.bytes().await.expect("should read body");
+        let torrent = TorrentFile::from_bytes(&replayed).expect("should parse");
+        assert_eq!(torrent.announce.as_deref(), Some(REDACTED));
+        for entry in read_dir(&path).expect("should read dir") {
+            let file =
+                read_sync(entry.expect("should read entry").path()).expect("should read file");
+            assert!(!String::from_utf8_lossy(&file).contains("0123456789"));
+        }
+        remove_dir_all(&path).expect("should remove dir");
+    }
+
+    #[tokio::test]
+    async fn replay_missing_recording() {
+        // Arrange
+        let cassette = Cassette::replay(get_path("missing"));
+
+        // Act
+        let error = cassette
+            .replay_response("GET", "action=index")
+            .await
+            .expect_err("should be an error");
+
+        // Assert
+        assert_eq!(error.operation, GazelleOperation::SendRequest);
+        assert!(!error.is_retryable());
+        assert!(error.source.to_string().contains("action=index"));
+    }
+}
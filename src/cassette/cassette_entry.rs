@@ -0,0 +1,24 @@
+use crate::prelude::*;
+
+/// A recorded request and its response.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) struct CassetteEntry {
+    /// HTTP method
+    pub method: String,
+    /// Query string of `ajax.php`
+    pub query: String,
+    /// HTTP status code
+    pub status: u16,
+    /// `Content-Type` header value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// `Retry-After` header value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<String>,
+    /// Body if it is valid UTF-8
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Name of the sibling file with the body if it isn't valid UTF-8
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_file: Option<String>,
+}
@@ -0,0 +1,11 @@
+use crate::prelude::*;
+
+/// Whether a [`Cassette`] records or replays.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CassetteMode {
+    /// Send requests to the indexer and write each response to the cassette
+    Record,
+    /// Serve responses from the cassette without sending any request
+    Replay,
+}
@@ -0,0 +1,9 @@
+pub use cassette::*;
+pub(crate) use cassette_entry::*;
+pub use cassette_mode::*;
+pub(crate) use redact::*;
+
+mod cassette;
+mod cassette_entry;
+mod cassette_mode;
+mod redact;
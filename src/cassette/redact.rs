@@ -0,0 +1,103 @@
+use crate::prelude::*;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Value written in place of a secret.
+pub(crate) const REDACTED: &str = "REDACTED";
+
+/// Top level keys of a `.torrent` file that contain the passkey.
+const ANNOUNCE_KEYS: [&[u8]; 2] = [b"announce", b"announce-list"];
+
+static SECRET_FIELD: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#""(passkey|authkey)"(\s*):(\s*)"[^"]*""#).expect("regex should be valid")
+});
+
+/// Replace the values of `passkey` and `authkey` fields in a JSON body.
+///
+/// - The rest of the text is unchanged so field order and malformed parts are kept
+pub(crate) fn redact_json(body: &str) -> String {
+    SECRET_FIELD
+        .replace_all(body, format!(r#""$1"$2:$3"{REDACTED}""#))
+        .into_owned()
+}
+
+/// Replace the announce URLs of a `.torrent` file as they contain the passkey.
+///
+/// - The `info` dictionary is copied exactly so the info hash is unchanged
+/// - A body that isn't a `.torrent` file is returned unchanged
+pub(crate) fn redact_torrent(body: &[u8]) -> Vec<u8> {
+    let Ok((root, info)) = decode_with_raw(body, b"info") else {
+        return body.to_vec();
+    };
+    let Some(entries) = root.as_dictionary() else {
+        return body.to_vec();
+    };
+    let mut output = vec![b'd'];
+    for (key, value) in entries {
+        output.extend(BencodeValue::Bytes(key.clone()).encode());
+        match info {
+            Some(info) if key == b"info" => output.extend_from_slice(info),
+            _ if ANNOUNCE_KEYS.contains(&key.as_slice()) => {
+                let redacted = BencodeValue::Bytes(REDACTED.as_bytes().to_vec());
+                let value = match value {
+                    BencodeValue::List(_) => {
+                        BencodeValue::List(vec![BencodeValue::List(vec![redacted])])
+                    }
+                    _ => redacted,
+                };
+                output.extend(value.encode());
+            }
+            _ => output.extend(value.encode()),
+        }
+    }
+    output.push(b'e');
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redact_json_secret_fields() {
+        // Arrange
+        let body = r#"{"status":"success","response":{"username":"user","authkey": "abc","passkey":"def"}}"#;
+
+        // Act
+        let output = redact_json(body);
+
+        // Assert
+        assert_eq!(
+            output,
+            r#"{"status":"success","response":{"username":"user","authkey": "REDACTED","passkey":"REDACTED"}}"#
+        );
+    }
+
+    #[test]
+    fn redact_json_keeps_malformed_response() {
+        let body = r#"{"status":"failure","response":[],"error":"bad id parameter"}"#;
+        assert_eq!(redact_json(body), body);
+    }
+
+    #[test]
+    fn redact_torrent_announce() {
+        // Arrange
+        let body = b"d8:announce39:https://example.com/0123456789/announce13:announce-listll39:https://example.com/0123456789/announceee4:infod6:lengthi1e4:name1:a12:piece lengthi1e6:pieces0:ee";
+        let before = TorrentFile::from_bytes(body).expect("should parse");
+
+        // Act
+        let output = redact_torrent(body);
+
+        // Assert
+        let after = TorrentFile::from_bytes(&output).expect("should parse");
+        assert_eq!(after.announce.as_deref(), Some(REDACTED));
+        assert_eq!(after.announce_list, vec![vec![REDACTED.to_owned()]]);
+        assert_eq!(after.info_hash(), before.info_hash());
+        assert!(!String::from_utf8_lossy(&output).contains("0123456789"));
+    }
+
+    #[test]
+    fn redact_torrent_not_bencoded() {
+        assert_eq!(redact_torrent(b"not a torrent"), b"not a torrent");
+    }
+}
@@ -1,5 +1,10 @@
 use crate::prelude::*;
-use reqwest::{Client, Response, StatusCode};
+use httpdate::parse_http_date;
+use reqwest::header::RETRY_AFTER;
+use reqwest::multipart::Form;
+use reqwest::{Client, Method, RequestBuilder, Response, StatusCode};
+use std::sync::Arc;
+use std::time::Instant;
 
 /// A client for the Gazelle API
 ///
@@ -11,34 +16,81 @@ pub struct GazelleClient {
     pub base_url: String,
     /// HTTP client with configured headers for authentication
     pub client: Client,
-    /// Rate limiter to throttle API requests
-    pub limiter: RateLimiter,
-    /// Delays between retry attempts on `TooManyRequests`.
+    /// Rate limiter to throttle API requests.
     ///
-    /// Empty vec disables retry.
-    pub retry_delays: Vec<Duration>,
+    /// May be shared with other clients for the same account.
+    pub limiter: Arc<RateLimiter>,
+    /// Strategy for retrying transient failures.
+    pub retry_policy: RetryPolicy,
+    /// Retry uploads after checking whether the failed attempt was actually accepted.
+    pub retry_uploads: bool,
+    /// Priority of requests waiting for the rate limiter.
+    ///
+    /// Pages of [`browse_all`](Self::browse_all) and
+    /// [`get_all_user_torrents`](Self::get_all_user_torrents) are always
+    /// [`RequestPriority::Background`].
+    pub priority: RequestPriority,
+    /// Record responses to a directory, or replay them without network.
+    pub cassette: Option<Cassette>,
+    /// Hooks called for each request and response.
+    pub middleware: Vec<Arc<dyn GazelleMiddleware>>,
 }
 
 impl From<GazelleClientOptions> for GazelleClient {
     /// Create a [`GazelleClient`] from [`GazelleClientOptions`]
     fn from(options: GazelleClientOptions) -> GazelleClient {
-        let factory = GazelleClientFactory { options };
+        let factory = GazelleClientFactory {
+            options,
+            limiter: None,
+            middleware: Vec::new(),
+        };
         factory.create()
     }
 }
 
 impl GazelleClient {
     pub(crate) async fn get<T: DeserializeOwned>(&self, query: String) -> Result<T, GazelleError> {
+        self.get_with_priority(query, self.priority).await
+    }
+
+    pub(crate) async fn get_with_priority<T: DeserializeOwned>(
+        &self,
+        query: String,
+        priority: RequestPriority,
+    ) -> Result<T, GazelleError> {
+        self.with_retry(|_| async {
+            let mut request = MiddlewareRequest::new(Method::GET, query.clone());
+            let result = self.get_internal(&mut request, priority).await;
+            let result = self.handle_result(&request, result).await;
+            self.record_rate_feedback(&result);
+            result
+        })
+        .await
+    }
+
+    /// Run `operation` until it succeeds or the retry policy gives up.
+    ///
+    /// - `operation` receives the 0-indexed attempt number
+    pub(crate) async fn with_retry<T, F, Fut>(&self, mut operation: F) -> Result<T, GazelleError>
+    where
+        F: FnMut(usize) -> Fut,
+        Fut: Future<Output = Result<T, GazelleError>>,
+    {
         let mut attempt = 0;
+        let start = Instant::now();
         loop {
-            let result = self.get_internal(&query).await;
-            match handle_result(result).await {
+            #[cfg(feature = "tracing")]
+            Self::record_attempt(attempt);
+            match operation(attempt).await {
                 Ok(value) => return Ok(value),
                 Err(error) => {
-                    if !error.is_retryable() {
+                    if !self.retry_policy.should_retry(&error) {
                         return Err(error);
                     }
-                    if !self.wait_before_retry(attempt).await {
+                    if !self
+                        .wait_before_retry(attempt, &error, start.elapsed())
+                        .await
+                    {
                         return Err(error);
                     }
                     attempt += 1;
@@ -47,59 +99,225 @@ impl GazelleClient {
         }
     }
 
-    pub(crate) async fn get_internal(&self, query: &str) -> Result<Response, ReqwestError> {
-        self.limiter.execute().await;
-        let path = format!("/ajax.php?{query}");
-        trace!("Sending request GET {path}");
-        let url = format!("{}{path}", self.base_url);
-        let start = SystemTime::now();
-        let result = self.client.get(url).send().await;
-        let elapsed = start
-            .elapsed()
-            .expect("elapsed should not fail")
-            .as_secs_f64();
+    pub(crate) async fn get_internal(
+        &self,
+        request: &mut MiddlewareRequest,
+        priority: RequestPriority,
+    ) -> Result<Response, GazelleError> {
+        if !self.is_replaying() {
+            self.wait_for_limiter(priority).await;
+        }
+        self.before_request(request);
+        let builder = self.client.get(self.get_url(request));
+        self.send(request, builder).await
+    }
+
+    /// Send a rate limited multipart POST.
+    ///
+    /// - Not retried as the form is consumed and the action may not be idempotent
+    /// - Use [`with_retry`](Self::with_retry) with a fresh form for each attempt where safe
+    pub(crate) async fn post<T: DeserializeOwned>(
+        &self,
+        action: &str,
+        form: Form,
+    ) -> Result<T, GazelleError> {
+        let mut request = MiddlewareRequest::new(Method::POST, format!("action={action}"));
+        let result = self.post_internal(&mut request, form).await;
+        let result = self.handle_result(&request, result).await;
+        self.record_rate_feedback(&result);
+        result
+    }
+
+    /// Let the rate limiter adapt to whether the indexer rejected the request as rate limited.
+    pub(crate) fn record_rate_feedback<T>(&self, result: &Result<T, GazelleError>) {
+        match result {
+            Ok(_) => self.limiter.record_success(),
+            Err(error)
+                if error.operation
+                    == GazelleOperation::ApiResponse(ApiResponseKind::TooManyRequests) =>
+            {
+                self.limiter.record_rate_limited();
+            }
+            Err(_) => {}
+        }
+    }
+
+    pub(crate) async fn post_internal(
+        &self,
+        request: &mut MiddlewareRequest,
+        form: Form,
+    ) -> Result<Response, GazelleError> {
+        if !self.is_replaying() {
+            self.wait_for_limiter(self.priority).await;
+        }
+        self.before_request(request);
+        let builder = self.client.post(self.get_url(request)).multipart(form);
+        self.send(request, builder).await
+    }
+
+    async fn wait_for_limiter(&self, priority: RequestPriority) {
+        #[cfg(feature = "tracing")]
+        let start = Instant::now();
+        self.limiter.execute(priority).await;
+        #[cfg(feature = "tracing")]
+        self.record_rate_limit_wait(start.elapsed());
+    }
+
+    /// Run an action, in a `tracing` span if the `tracing` feature is enabled.
+    #[cfg(not(feature = "tracing"))]
+    pub(crate) async fn instrument_action<T>(
+        &self,
+        _action: &'static str,
+        future: impl Future<Output = Result<T, GazelleError>>,
+    ) -> Result<T, GazelleError> {
+        future.await
+    }
+
+    /// Host of the indexer for logs and metrics.
+    ///
+    /// Example: `orpheus.network`
+    pub(crate) fn host(&self) -> &str {
+        display_base_url(&self.base_url)
+    }
+
+    /// Read and parse the response, then pass it to the middleware.
+    async fn handle_result<T: DeserializeOwned>(
+        &self,
+        request: &MiddlewareRequest,
+        result: Result<Response, GazelleError>,
+    ) -> Result<T, GazelleError> {
+        let response = get_response(result).await;
+        let (status, body_size) = match &response {
+            Ok((status_code, _, json)) => (Some(*status_code), u64::try_from(json.len()).ok()),
+            Err(_) => (None, None),
+        };
+        let result = response.and_then(|(status_code, retry_after, json)| {
+            parse_response(request.action(), status_code, retry_after, json)
+        });
+        self.after_response(request, status, body_size, result.as_ref().err());
+        result
+    }
+
+    fn get_url(&self, request: &MiddlewareRequest) -> String {
+        format!("{}{}?{}", self.base_url, request.path, request.query)
+    }
+
+    /// Send the request, recording or replaying it if a [`Cassette`] is set.
+    async fn send(
+        &self,
+        request: &mut MiddlewareRequest,
+        builder: RequestBuilder,
+    ) -> Result<Response, GazelleError> {
+        trace!(
+            "Sending request {} {}?{}",
+            request.method, request.path, request.query
+        );
+        let builder = builder.headers(request.headers.clone());
+        request.mark_sent();
+        let method = request.method.as_str();
+        let query = request.query.as_str();
+        let result = match &self.cassette {
+            None => builder.send().await.map_err(GazelleError::request),
+            Some(cassette) if cassette.mode == CassetteMode::Replay => {
+                cassette.replay_response(method, query).await
+            }
+            Some(cassette) => match builder.send().await {
+                Ok(response) => cassette.record_response(method, query, response).await,
+                Err(error) => Err(GazelleError::request(error)),
+            },
+        };
+        let elapsed = request.elapsed().as_secs_f64();
         trace!("Received response after {elapsed:.3}");
         result
     }
 
-    /// Log a retry warning and sleep for the configured delay before the next attempt.
+    /// Pass the request to each [`GazelleMiddleware`] in order.
+    fn before_request(&self, request: &mut MiddlewareRequest) {
+        for layer in &self.middleware {
+            layer.on_request(request);
+        }
+    }
+
+    /// Pass the response to each [`GazelleMiddleware`] in reverse order.
+    pub(crate) fn after_response(
+        &self,
+        request: &MiddlewareRequest,
+        status: Option<StatusCode>,
+        body_size: Option<u64>,
+        error: Option<&GazelleError>,
+    ) {
+        let response = MiddlewareResponse {
+            status,
+            elapsed: request.elapsed(),
+            body_size,
+            error,
+        };
+        #[cfg(feature = "tracing")]
+        self.record_response(request, &response);
+        for layer in self.middleware.iter().rev() {
+            layer.on_response(request, &response);
+        }
+    }
+
+    fn is_replaying(&self) -> bool {
+        self.cassette
+            .as_ref()
+            .is_some_and(|cassette| cassette.mode == CassetteMode::Replay)
+    }
+
+    /// Log a retry warning and sleep for the delay given by the retry policy.
+    ///
+    /// - A `Retry-After` header on `error` takes precedence over the policy delay
     ///
     /// Returns `true` when a delay was waited and the caller should retry, or
     /// `false` when retries are exhausted and the caller should give up.
-    async fn wait_before_retry(&self, attempt: usize) -> bool {
-        let delay = next_retry_delay(attempt, &self.retry_delays);
-        let host = display_base_url(&self.base_url);
-        let total = self.retry_delays.len() + 1;
+    async fn wait_before_retry(
+        &self,
+        attempt: usize,
+        error: &GazelleError,
+        elapsed: Duration,
+    ) -> bool {
+        let delay = self
+            .retry_policy
+            .get_delay(attempt, error.retry_after(), elapsed);
+        let reason = describe_failure(error, self.host());
+        let total = self.retry_policy.max_attempts();
         let current = attempt + 1;
         if let Some(delay) = delay {
+            #[cfg(feature = "tracing")]
+            self.record_retry(error);
             warn!(
-                "Rate limited by {host}, attempt {current} of {total} failed, retrying in {:.1}s",
+                "{reason}, attempt {current} of {total} failed, retrying in {:.1}s",
                 delay.as_secs_f64(),
             );
             tokio_sleep(delay).await;
             true
         } else {
-            warn!("Rate limited by {host}, attempt {current} of {total} failed");
+            warn!("{reason}, attempt {current} of {total} failed");
             false
         }
     }
 }
 
-pub(crate) async fn handle_result<T: DeserializeOwned>(
-    result: Result<Response, ReqwestError>,
+pub(crate) fn parse_response<T: DeserializeOwned>(
+    action: Option<&str>,
+    status_code: StatusCode,
+    retry_after: Option<Duration>,
+    json: String,
 ) -> Result<T, GazelleError> {
-    let (status_code, json) = get_response(result).await?;
-    let response = deserialize(json)?;
-    get_result(status_code, response)
+    let response = deserialize_with_status(status_code, json)
+        .map_err(|error| error.with_retry_after(retry_after))?;
+    get_result(action, status_code, response).map_err(|error| error.with_retry_after(retry_after))
 }
 
 pub(crate) async fn get_response(
-    result: Result<Response, ReqwestError>,
-) -> Result<(StatusCode, String), GazelleError> {
-    let response = result.map_err(GazelleError::request)?;
+    result: Result<Response, GazelleError>,
+) -> Result<(StatusCode, Option<Duration>, String), GazelleError> {
+    let response = result?;
     let status_code = response.status();
+    let retry_after = get_retry_after(&response);
     let json = response.text().await.map_err(GazelleError::response)?;
-    Ok((status_code, json))
+    Ok((status_code, retry_after, json))
 }
 
 pub(crate) fn deserialize<T: DeserializeOwned>(
@@ -110,14 +328,26 @@ pub(crate) fn deserialize<T: DeserializeOwned>(
     json_from_str(&json).map_err(GazelleError::deserialization)
 }
 
+/// Deserialize the body, preferring a status code error if the body is not JSON.
+///
+/// - Proxies such as Cloudflare return HTML pages for `429` and `5xx` responses
+pub(crate) fn deserialize_with_status<T: DeserializeOwned>(
+    status_code: StatusCode,
+    json: String,
+) -> Result<ApiResponse<T>, GazelleError> {
+    deserialize(json)
+        .map_err(|error| GazelleError::match_status_error(status_code, None).unwrap_or(error))
+}
+
 pub(crate) fn get_result<T: DeserializeOwned>(
+    action: Option<&str>,
     status_code: StatusCode,
     response: ApiResponse<T>,
 ) -> Result<T, GazelleError> {
     let status = status_code.as_u16();
     if let Some(message) = &response.error {
         trace!("Received {status_code} response with error: {message}");
-        if let Some(error) = GazelleError::match_response_error(message, status) {
+        if let Some(error) = GazelleError::match_response_error(action, message, status) {
             return Err(error);
         }
     } else {
@@ -131,12 +361,34 @@ pub(crate) fn get_result<T: DeserializeOwned>(
         .ok_or_else(|| GazelleError::other(response.error.unwrap_or_default(), status))
 }
 
-/// Return the delay before the next attempt, or `None` if no more retries are configured.
+/// Get the delay requested by the `Retry-After` header of a response.
+pub(crate) fn get_retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    parse_retry_after(value, SystemTime::now())
+}
+
+/// Parse a `Retry-After` header value.
+///
+/// - Accepts either a number of seconds or an HTTP date
+/// - Dates in the past are treated as no delay
 ///
-/// `attempt` is 0-indexed: attempt 0 is the initial request, attempt 1 is the first retry.
-/// `delays[i]` is the gap before attempt `i + 1`.
-fn next_retry_delay(attempt: usize, delays: &[Duration]) -> Option<Duration> {
-    delays.get(attempt).copied()
+/// <https://www.rfc-editor.org/rfc/rfc9110#field.retry-after>
+fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = parse_http_date(value).ok()?;
+    Some(date.duration_since(now).unwrap_or_default())
+}
+
+/// Describe a retryable failure for logging.
+fn describe_failure(error: &GazelleError, host: &str) -> String {
+    match error.retry_class() {
+        Some(RetryClass::RateLimited) => format!("Rate limited by {host}"),
+        Some(RetryClass::ServerError) => format!("{host} is unavailable ({})", error.operation),
+        Some(RetryClass::Transport) | None => format!("Request to {host} failed: {error}"),
+    }
 }
 
 /// Strip scheme and trailing slash from a base URL for display.
@@ -168,14 +420,55 @@ impl GazelleClientTrait for GazelleClient {
         GazelleClient::get_torrent_group(self, id).await
     }
 
+    async fn get_artist(&self, artist: ArtistLookup) -> Result<ArtistResponse, GazelleError> {
+        GazelleClient::get_artist(self, artist).await
+    }
+
+    async fn search_requests(
+        &self,
+        search: &RequestSearch,
+    ) -> Result<RequestSearchResponse, GazelleError> {
+        GazelleClient::search_requests(self, search).await
+    }
+
+    async fn get_request(&self, id: u32) -> Result<RequestDetail, GazelleError> {
+        GazelleClient::get_request(self, id).await
+    }
+
     async fn get_user(&self, id: u32) -> Result<User, GazelleError> {
         GazelleClient::get_user(self, id).await
     }
 
+    async fn get_user_torrents(
+        &self,
+        user_id: u32,
+        kind: UserTorrentKind,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<UserTorrent>, GazelleError> {
+        GazelleClient::get_user_torrents(self, user_id, kind, limit, offset).await
+    }
+
+    async fn get_index(&self) -> Result<IndexResponse, GazelleError> {
+        GazelleClient::get_index(self).await
+    }
+
+    async fn fill_request(
+        &self,
+        request_id: u32,
+        torrent_id: u32,
+    ) -> Result<FillRequestResponse, GazelleError> {
+        GazelleClient::fill_request(self, request_id, torrent_id).await
+    }
+
     async fn download_torrent(&self, id: u32) -> Result<Vec<u8>, GazelleError> {
         GazelleClient::download_torrent(self, id).await
     }
 
+    async fn download_torrent_to_path(&self, id: u32, path: &Path) -> Result<u64, GazelleError> {
+        GazelleClient::download_torrent_to_path(self, id, path).await
+    }
+
     async fn upload_torrent(&self, upload: UploadForm) -> Result<UploadResponse, GazelleError> {
         GazelleClient::upload_torrent(self, upload).await
     }
@@ -184,6 +477,96 @@ impl GazelleClientTrait for GazelleClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn client_with_retries(retries: usize) -> GazelleClient {
+        GazelleClient::from(GazelleClientOptions {
+            retry_delays: vec![Duration::from_millis(1); retries],
+            ..get_test_options("http://127.0.0.1:1")
+        })
+    }
+
+    #[tokio::test]
+    async fn with_retry_until_success() {
+        // Arrange
+        let client = client_with_retries(2);
+        let calls = AtomicUsize::new(0);
+
+        // Act
+        let result = client
+            .with_retry(|attempt| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if attempt < 2 {
+                        Err(GazelleError::too_many_requests(String::new(), 429))
+                    } else {
+                        Ok(attempt)
+                    }
+                }
+            })
+            .await;
+
+        // Assert
+        assert_eq!(result.expect("should succeed"), 2);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn with_retry_exhausted() {
+        // Arrange
+        let client = client_with_retries(1);
+        let calls = AtomicUsize::new(0);
+
+        // Act
+        let result: Result<(), _> = client
+            .with_retry(|_| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async { Err(GazelleError::too_many_requests(String::new(), 429)) }
+            })
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn with_retry_delays_only_rate_limited() {
+        // Arrange
+        let client = client_with_retries(3);
+        let calls = AtomicUsize::new(0);
+
+        // Act
+        let result: Result<(), _> = client
+            .with_retry(|_| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async { Err(GazelleError::service_unavailable(String::new(), 503)) }
+            })
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn with_retry_not_retryable() {
+        // Arrange
+        let client = client_with_retries(3);
+        let calls = AtomicUsize::new(0);
+
+        // Act
+        let result: Result<(), _> = client
+            .with_retry(|_| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async { Err(GazelleError::not_found(String::new(), 404)) }
+            })
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
 
     #[test]
     fn deserialize_success_response() {
@@ -247,6 +630,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_with_status_html_gateway_error() {
+        // Arrange
+        let html = "<html><body>502 Bad Gateway</body></html>".to_owned();
+
+        // Act
+        let result: Result<ApiResponse<JsonValue>, _> =
+            deserialize_with_status(StatusCode::BAD_GATEWAY, html);
+
+        // Assert
+        let error = result.expect_err("html should return error");
+        assert_eq!(
+            error.operation,
+            GazelleOperation::ApiResponse(ApiResponseKind::BadGateway)
+        );
+    }
+
+    #[test]
+    fn deserialize_with_status_html_ok() {
+        // Arrange
+        let html = "<html></html>".to_owned();
+
+        // Act
+        let result: Result<ApiResponse<JsonValue>, _> =
+            deserialize_with_status(StatusCode::OK, html);
+
+        // Assert
+        let error = result.expect_err("html should return error");
+        assert_eq!(error.operation, GazelleOperation::Deserialize);
+    }
+
     #[test]
     fn get_result_success_extracts_response() {
         // Arrange
@@ -257,7 +671,7 @@ mod tests {
         };
 
         // Act
-        let result = get_result(StatusCode::OK, response);
+        let result = get_result(None, StatusCode::OK, response);
 
         // Assert
         assert_eq!(result.expect("success response should extract"), 42);
@@ -273,7 +687,7 @@ mod tests {
         };
 
         // Act
-        let result = get_result(StatusCode::OK, response);
+        let result = get_result(None, StatusCode::OK, response);
 
         // Assert
         let error = result.expect_err("response error should return error");
@@ -293,7 +707,7 @@ mod tests {
         };
 
         // Act
-        let result = get_result(StatusCode::BAD_REQUEST, response);
+        let result = get_result(None, StatusCode::BAD_REQUEST, response);
 
         // Assert
         let error = result.expect_err("status error should return error");
@@ -313,7 +727,7 @@ mod tests {
         };
 
         // Act
-        let result = get_result(StatusCode::OK, response);
+        let result = get_result(None, StatusCode::OK, response);
 
         // Assert
         let error = result.expect_err("missing response should return error");
@@ -337,7 +751,7 @@ mod tests {
         };
 
         // Act - Status is 400 but error message indicates rate limit
-        let result = get_result(StatusCode::BAD_REQUEST, response);
+        let result = get_result(None, StatusCode::BAD_REQUEST, response);
 
         // Assert - Rate limit error takes priority
         let error = result.expect_err("rate limit should return error");
@@ -357,7 +771,7 @@ mod tests {
         };
 
         // Act
-        let result = get_result(StatusCode::OK, response);
+        let result = get_result(None, StatusCode::OK, response);
 
         // Assert - Falls through to Other
         let error = result.expect_err("unknown error should fall through");
@@ -373,22 +787,35 @@ mod tests {
     }
 
     #[test]
-    fn next_retry_delay_within_bounds() {
-        let delays = [Duration::from_secs(5), Duration::from_secs(10)];
-        assert_eq!(next_retry_delay(0, &delays), Some(Duration::from_secs(5)));
-        assert_eq!(next_retry_delay(1, &delays), Some(Duration::from_secs(10)));
+    fn parse_retry_after_seconds() {
+        let now = SystemTime::now();
+        assert_eq!(parse_retry_after("120", now), Some(Duration::from_mins(2)));
+        assert_eq!(parse_retry_after(" 5 ", now), Some(Duration::from_secs(5)));
     }
 
     #[test]
-    fn next_retry_delay_exhausted() {
-        let delays = [Duration::from_secs(5), Duration::from_secs(10)];
-        assert_eq!(next_retry_delay(2, &delays), None);
+    fn parse_retry_after_http_date() {
+        // Arrange
+        let now = parse_http_date("Wed, 21 Oct 2015 07:28:00 GMT").expect("should parse");
+
+        // Act
+        let delay = parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now);
+
+        // Assert
+        assert_eq!(delay, Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn parse_retry_after_past_date() {
+        let now = parse_http_date("Wed, 21 Oct 2015 07:28:00 GMT").expect("should parse");
+        let delay = parse_retry_after("Wed, 21 Oct 2015 07:27:00 GMT", now);
+        assert_eq!(delay, Some(Duration::ZERO));
     }
 
     #[test]
-    fn next_retry_delay_empty() {
-        let delays: [Duration; 0] = [];
-        assert_eq!(next_retry_delay(0, &delays), None);
+    fn parse_retry_after_invalid() {
+        assert_eq!(parse_retry_after("soon", SystemTime::now()), None);
+        assert_eq!(parse_retry_after("-1", SystemTime::now()), None);
     }
 
     #[test]
@@ -413,4 +840,27 @@ mod tests {
     fn display_base_url_trailing_slash() {
         assert_eq!(display_base_url("https://example.com/"), "example.com");
     }
+
+    #[test]
+    fn record_rate_feedback_adapts_limiter() {
+        // Arrange
+        let mut client = client_with_retries(0);
+        client.limiter = Arc::new(RateLimiter::new(4, Duration::from_secs(10)).with_adaptive(
+            AdaptiveRate {
+                min_num: 1,
+                recover_after: 1,
+            },
+        ));
+        let rate_limited: Result<(), GazelleError> =
+            Err(GazelleError::too_many_requests(String::new(), 429));
+        let not_found: Result<(), GazelleError> = Err(GazelleError::not_found(String::new(), 404));
+
+        // Act & Assert
+        client.record_rate_feedback(&rate_limited);
+        assert_eq!(client.limiter.get_rate().num, 2);
+        client.record_rate_feedback(&not_found);
+        assert_eq!(client.limiter.get_rate().num, 2);
+        client.record_rate_feedback(&Ok(()));
+        assert_eq!(client.limiter.get_rate().num, 3);
+    }
 }
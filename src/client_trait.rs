@@ -18,12 +18,46 @@ pub trait GazelleClientTrait: Send + Sync {
     /// Get a torrent group by id
     async fn get_torrent_group(&self, id: u32) -> Result<GroupResponse, GazelleError>;
 
+    /// Get an artist by id or name
+    async fn get_artist(&self, artist: ArtistLookup) -> Result<ArtistResponse, GazelleError>;
+
+    /// Search requests
+    async fn search_requests(
+        &self,
+        search: &RequestSearch,
+    ) -> Result<RequestSearchResponse, GazelleError>;
+
+    /// Get a request by id
+    async fn get_request(&self, id: u32) -> Result<RequestDetail, GazelleError>;
+
+    /// Fill a request with an existing torrent
+    async fn fill_request(
+        &self,
+        request_id: u32,
+        torrent_id: u32,
+    ) -> Result<FillRequestResponse, GazelleError>;
+
     /// Get a user by id
     async fn get_user(&self, id: u32) -> Result<User, GazelleError>;
 
+    /// Get a page of a user's seeding, leeching, uploaded or snatched torrents
+    async fn get_user_torrents(
+        &self,
+        user_id: u32,
+        kind: UserTorrentKind,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<UserTorrent>, GazelleError>;
+
+    /// Get the account that owns the API key
+    async fn get_index(&self) -> Result<IndexResponse, GazelleError>;
+
     /// Download torrent file content
     async fn download_torrent(&self, id: u32) -> Result<Vec<u8>, GazelleError>;
 
+    /// Save the .torrent file to a path, returning the number of bytes written
+    async fn download_torrent_to_path(&self, id: u32, path: &Path) -> Result<u64, GazelleError>;
+
     /// Upload a torrent
     async fn upload_torrent(&self, upload: UploadForm) -> Result<UploadResponse, GazelleError>;
 }
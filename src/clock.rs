@@ -0,0 +1,21 @@
+use tokio::time::Instant;
+
+/// Source of the current time for a [`RateLimiter`](crate::RateLimiter).
+pub trait Clock: Send + Sync {
+    /// Get the current time.
+    fn now(&self) -> Instant;
+}
+
+/// A [`Clock`] backed by the monotonic [`tokio::time::Instant`].
+///
+/// - Never goes backwards when the system clock is adjusted
+/// - Follows `tokio::time::pause` and `tokio::time::advance` so tests don't need
+///   real sleeps
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioClock;
+
+impl Clock for TokioClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
@@ -0,0 +1,22 @@
+use crate::prelude::*;
+
+/// A candidate found by [`find_cross_seeds`].
+#[derive(Clone, Debug)]
+pub struct CrossSeedMatch {
+    /// Group ID of the candidate
+    pub group_id: u32,
+    /// Candidate torrent including its file list
+    pub torrent: Torrent,
+    /// Similarity of the file lists from `0.0` to `1.0`
+    ///
+    /// See [`score_files`].
+    pub confidence: f64,
+}
+
+impl CrossSeedMatch {
+    /// Are the file lists identical?
+    #[must_use]
+    pub fn is_exact(&self) -> bool {
+        self.confidence >= 1.0
+    }
+}
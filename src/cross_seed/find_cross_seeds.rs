@@ -0,0 +1,181 @@
+use crate::prelude::*;
+
+/// Maximum number of candidate groups to fetch file lists for.
+const MAX_GROUPS: usize = 10;
+
+/// Find torrents on another indexer with the same files as `source`.
+///
+/// - `client` is the indexer to search, not the one `source` came from
+/// - Searches with the queries from [`get_cross_seed_requests`]
+/// - Fetches the file lists of the first 10 candidate groups
+/// - A candidate group that fails to fetch is logged and skipped
+/// - Scores each candidate with [`score_files`] and drops those without any matching file
+///
+/// Returns matches ranked by confidence, highest first, or an error if a search fails.
+pub async fn find_cross_seeds(
+    client: &dyn GazelleClientTrait,
+    source: &TorrentResponse,
+) -> Result<Vec<CrossSeedMatch>, GazelleError> {
+    let mut candidates: Vec<(u32, Vec<u32>)> = Vec::new();
+    for request in get_cross_seed_requests(&source.group, &source.torrent) {
+        let response = client.browse(&request).await?;
+        for group in response.results {
+            let ids = group.torrents.iter().map(|torrent| torrent.torrent_id);
+            match candidates.iter_mut().find(|(id, _)| *id == group.group_id) {
+                Some((_, torrent_ids)) => torrent_ids.extend(ids),
+                None => candidates.push((group.group_id, ids.collect())),
+            }
+        }
+    }
+    trace!("Found {} cross seed candidate groups", candidates.len());
+    let source_files = source.torrent.get_files();
+    let mut matches = Vec::new();
+    for (group_id, torrent_ids) in candidates.into_iter().take(MAX_GROUPS) {
+        let response = match client.get_torrent_group(group_id).await {
+            Ok(response) => response,
+            Err(error) => {
+                warn!("Skipping cross seed candidate group {group_id}: {error}");
+                continue;
+            }
+        };
+        for torrent in response.torrents {
+            if !torrent_ids.contains(&torrent.id) {
+                continue;
+            }
+            let confidence = score_files(&source_files, &torrent.get_files());
+            if confidence > 0.0 {
+                matches.push(CrossSeedMatch {
+                    group_id,
+                    torrent,
+                    confidence,
+                });
+            }
+        }
+    }
+    matches.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    Ok(matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[tokio::test]
+    #[cfg(feature = "mock")]
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "test assertions on known mock data"
+    )]
+    async fn find_cross_seeds_ranks_matches() {
+        // Arrange
+        let source = TorrentResponse::mock();
+        let partial = Torrent {
+            id: 457,
+            file_list: Some("test.flac{{{100000}}}|||extra.log{{{100000}}}".to_owned()),
+            ..Torrent::mock()
+        };
+        let unrelated = Torrent {
+            id: 458,
+            file_list: Some("other.flac{{{100000}}}".to_owned()),
+            ..Torrent::mock()
+        };
+        let not_in_results = Torrent {
+            id: 459,
+            ..Torrent::mock()
+        };
+        let mut browse = BrowseResponse::mock();
+        browse.results[0].torrents = [456, 457, 458]
+            .into_iter()
+            .map(|torrent_id| BrowseTorrent {
+                torrent_id,
+                ..BrowseTorrent::mock()
+            })
+            .collect();
+        let client = MockGazelleClient::new()
+            .with_browse(Ok(browse))
+            .with_get_torrent_group(Ok(GroupResponse {
+                group: Group::mock(),
+                torrents: vec![unrelated, partial, Torrent::mock(), not_in_results],
+            }));
+
+        // Act
+        let matches = find_cross_seeds(&client, &source)
+            .await
+            .expect("should find matches");
+
+        // Assert
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].torrent.id, 456);
+        assert_eq!(matches[0].group_id, 123);
+        assert!(matches[0].is_exact());
+        assert_eq!(matches[1].torrent.id, 457);
+        assert!(!matches[1].is_exact());
+        assert!(matches[1].confidence > 0.0);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "mock")]
+    #[expect(
+        clippy::indexing_slicing,
+        reason = "test assertions on known mock data"
+    )]
+    async fn find_cross_seeds_skips_failed_groups() {
+        // Arrange
+        let mut browse = BrowseResponse::mock();
+        let missing = BrowseGroup {
+            group_id: 124,
+            ..browse.results[0].clone()
+        };
+        browse.results.insert(0, missing);
+        let client = MockGazelleClient::new()
+            .with_browse(Ok(browse))
+            .on_get_torrent_group(
+                MockRule::matching(124)
+                    .returns(Err(GazelleError::not_found("not found".to_owned(), 404))),
+            )
+            .with_get_torrent_group(Ok(GroupResponse::mock()));
+
+        // Act
+        let matches = find_cross_seeds(&client, &TorrentResponse::mock())
+            .await
+            .expect("should find matches");
+
+        // Assert
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].group_id, 123);
+        assert!(matches[0].is_exact());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "mock")]
+    async fn find_cross_seeds_propagates_errors() {
+        // Arrange
+        let client = MockGazelleClient::new()
+            .with_browse(Err(GazelleError::not_found("not found".to_owned(), 404)));
+
+        // Act
+        let result = find_cross_seeds(&client, &TorrentResponse::mock()).await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    #[serial]
+    #[ignore = "integration test requiring API credentials"]
+    async fn find_cross_seeds_finds_source() -> Result<(), GazelleError> {
+        for_each_indexer(|name, client, examples| async move {
+            let client = client.lock().await;
+            let source = client.get_torrent(examples.torrent).await?;
+            let matches = find_cross_seeds(&*client, &source).await?;
+            let found = matches
+                .iter()
+                .find(|candidate| candidate.torrent.id == examples.torrent)
+                .expect("source torrent should be a candidate");
+            assert!(found.is_exact(), "[{name}] source should match exactly");
+            Ok(())
+        })
+        .await
+    }
+}
@@ -0,0 +1,144 @@
+use crate::prelude::*;
+
+/// Build browse queries to find a [`Torrent`] on another indexer.
+///
+/// - Every query is filtered by the media, format and encoding of the torrent
+/// - The first query searches by artist, album and year
+/// - The second query searches the file lists for the name of the largest file
+///
+/// The largest file is the most likely to have a distinctive name, and the
+/// file list query still finds groups that were titled differently.
+#[must_use]
+pub fn get_cross_seed_requests(group: &Group, torrent: &Torrent) -> Vec<BrowseRequest> {
+    let base = BrowseRequest {
+        category: Some(group.category_id),
+        media: Some(torrent.media.clone()),
+        format: Some(torrent.format.clone()),
+        encoding: Some(torrent.encoding.clone()),
+        ..BrowseRequest::default()
+    };
+    let artist = group
+        .music_info
+        .as_ref()
+        .and_then(|credits| credits.artists.first())
+        .map(|credit| credit.name.clone());
+    let mut requests = vec![BrowseRequest {
+        artist,
+        album: Some(group.name.clone()),
+        year: Some(u32::from(group.year)),
+        ..base.clone()
+    }];
+    if let Some(file_name) = get_largest_file_name(torrent) {
+        requests.push(BrowseRequest {
+            filelist: Some(file_name),
+            ..base
+        });
+    }
+    requests
+}
+
+/// Name of the largest file without its parent directories.
+fn get_largest_file_name(torrent: &Torrent) -> Option<String> {
+    let file = torrent
+        .get_files()
+        .into_iter()
+        .max_by_key(|file| file.size)?;
+    file.name
+        .rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty())
+        .map(ToOwned::to_owned)
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::indexing_slicing,
+    reason = "test assertions on known request count"
+)]
+mod tests {
+    use super::*;
+
+    fn group() -> Group {
+        Group {
+            name: "Album".to_owned(),
+            year: 2020,
+            music_info: Some(Credits {
+                artists: vec![
+                    Credit {
+                        id: 1,
+                        name: "Artist".to_owned(),
+                    },
+                    Credit {
+                        id: 2,
+                        name: "Other Artist".to_owned(),
+                    },
+                ],
+                ..Credits::default()
+            }),
+            ..Group::default()
+        }
+    }
+
+    fn torrent() -> Torrent {
+        Torrent {
+            media: Media::CD,
+            format: Format::FLAC,
+            encoding: Quality::Lossless,
+            file_list: Some(
+                "CD 1/01 Intro.flac{{{100}}}|||CD 1/02 Long Song.flac{{{300}}}|||cover.jpg{{{50}}}"
+                    .to_owned(),
+            ),
+            ..Torrent::default()
+        }
+    }
+
+    #[test]
+    fn get_cross_seed_requests_metadata() {
+        // Arrange & Act
+        let requests = get_cross_seed_requests(&group(), &torrent());
+
+        // Assert
+        assert_eq!(requests.len(), 2);
+        let request = &requests[0];
+        assert_eq!(request.artist.as_deref(), Some("Artist"));
+        assert_eq!(request.album.as_deref(), Some("Album"));
+        assert_eq!(request.year, Some(2020));
+        assert_eq!(request.media, Some(Media::CD));
+        assert_eq!(request.format, Some(Format::FLAC));
+        assert_eq!(request.encoding, Some(Quality::Lossless));
+        assert!(request.filelist.is_none());
+    }
+
+    #[test]
+    fn get_cross_seed_requests_file_list() {
+        // Arrange & Act
+        let requests = get_cross_seed_requests(&group(), &torrent());
+
+        // Assert
+        let request = &requests[1];
+        assert_eq!(request.filelist.as_deref(), Some("02 Long Song.flac"));
+        assert_eq!(request.media, Some(Media::CD));
+        assert!(request.artist.is_none());
+        assert!(request.album.is_none());
+    }
+
+    #[test]
+    fn get_cross_seed_requests_without_files() {
+        // Arrange
+        let group = Group {
+            music_info: None,
+            ..group()
+        };
+        let torrent = Torrent {
+            file_list: None,
+            ..torrent()
+        };
+
+        // Act
+        let requests = get_cross_seed_requests(&group, &torrent);
+
+        // Assert
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].artist.is_none());
+    }
+}
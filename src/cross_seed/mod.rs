@@ -0,0 +1,9 @@
+pub use cross_seed_match::*;
+pub use find_cross_seeds::*;
+pub use get_cross_seed_requests::*;
+pub use score_files::*;
+
+mod cross_seed_match;
+mod find_cross_seeds;
+mod get_cross_seed_requests;
+mod score_files;
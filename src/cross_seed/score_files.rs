@@ -0,0 +1,74 @@
+use crate::prelude::*;
+use std::collections::HashSet;
+
+/// Score how closely two file lists match.
+///
+/// - Files match when both the relative path and size are equal
+/// - Weighted by size so a matching audio file counts for more than a matching log
+/// - Returns `1.0` for identical file lists and `0.0` when nothing matches
+#[must_use]
+#[expect(
+    clippy::as_conversions,
+    clippy::cast_precision_loss,
+    reason = "byte counts only need to be approximate for a ratio"
+)]
+pub fn score_files(source: &[FileItem], candidate: &[FileItem]) -> f64 {
+    let candidate_set: HashSet<&FileItem> = candidate.iter().collect();
+    let matched: u64 = source
+        .iter()
+        .filter(|file| candidate_set.contains(file))
+        .map(|file| file.size)
+        .sum();
+    let total: u64 = source
+        .iter()
+        .chain(candidate.iter())
+        .map(|file| file.size)
+        .sum();
+    if total == 0 {
+        return 0.0;
+    }
+    (2 * matched) as f64 / total as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(name: &str, size: u64) -> FileItem {
+        FileItem {
+            name: name.to_owned(),
+            size,
+        }
+    }
+
+    #[test]
+    fn score_files_identical() {
+        let files = vec![file("01.flac", 300), file("cover.jpg", 100)];
+        assert!((score_files(&files, &files) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn score_files_partial() {
+        // Arrange
+        let source = vec![file("01.flac", 300), file("cover.jpg", 100)];
+        let candidate = vec![file("01.flac", 300), file("folder.jpg", 100)];
+
+        // Act
+        let score = score_files(&source, &candidate);
+
+        // Assert
+        assert!((score - 0.75).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn score_files_size_mismatch() {
+        let source = vec![file("01.flac", 300)];
+        let candidate = vec![file("01.flac", 301)];
+        assert!(score_files(&source, &candidate).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn score_files_empty() {
+        assert!(score_files(&[], &[]).abs() < f64::EPSILON);
+    }
+}
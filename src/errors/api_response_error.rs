@@ -6,4 +6,6 @@ use crate::prelude::*;
 pub struct ApiResponseError {
     pub message: String,
     pub status: u16,
+    /// Delay requested by the `Retry-After` header
+    pub retry_after: Option<Duration>,
 }
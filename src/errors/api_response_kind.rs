@@ -8,14 +8,36 @@ pub enum ApiResponseKind {
     Unauthorized,
     NotFound,
     TooManyRequests,
+    /// 502 from the server or its proxy.
+    BadGateway,
+    /// 503 from the server or its proxy.
+    ServiceUnavailable,
+    /// 504 from the server or its proxy.
+    GatewayTimeout,
+    /// The request has already been filled.
+    RequestAlreadyFilled,
+    /// The torrent does not satisfy the request.
+    RequestMismatch,
     Other,
 }
 
 impl ApiResponseKind {
-    /// Whether the response indicates a transient rate-limit that warrants retry.
+    /// Whether the response indicates a transient failure that warrants retry.
     #[must_use]
     pub fn is_retryable(self) -> bool {
-        self == Self::TooManyRequests
+        self.retry_class().is_some()
+    }
+
+    /// Get the [`RetryClass`] if the response indicates a transient failure.
+    #[must_use]
+    pub fn retry_class(self) -> Option<RetryClass> {
+        match self {
+            Self::TooManyRequests => Some(RetryClass::RateLimited),
+            Self::BadGateway | Self::ServiceUnavailable | Self::GatewayTimeout => {
+                Some(RetryClass::ServerError)
+            }
+            _ => None,
+        }
     }
 
     /// Whether the response indicates the requested resource is missing.
@@ -35,6 +57,11 @@ impl Display for ApiResponseKind {
             Self::Unauthorized => write!(f, "unauthorized"),
             Self::NotFound => write!(f, "not found"),
             Self::TooManyRequests => write!(f, "too many requests"),
+            Self::BadGateway => write!(f, "bad gateway"),
+            Self::ServiceUnavailable => write!(f, "service unavailable"),
+            Self::GatewayTimeout => write!(f, "gateway timeout"),
+            Self::RequestAlreadyFilled => write!(f, "request already filled"),
+            Self::RequestMismatch => write!(f, "torrent does not match request"),
             Self::Other => write!(f, "unexpected response"),
         }
     }
@@ -0,0 +1,11 @@
+use crate::prelude::*;
+
+/// An invalid value in [`GazelleClientOptions`].
+#[derive(Clone, Debug, ThisError)]
+#[error("invalid {option}: {message}")]
+pub struct ConfigureError {
+    /// Name of the option
+    pub option: String,
+    /// Why the value is invalid
+    pub message: String,
+}
@@ -7,6 +7,7 @@ pub enum ErrorSource {
     SerdeJson(JsonError),
     Io(IoError),
     ApiResponse(ApiResponseError),
+    Configure(ConfigureError),
     Stringified(String),
 }
 
@@ -17,6 +18,7 @@ impl Display for ErrorSource {
             Self::SerdeJson(e) => write!(f, "{e}"),
             Self::Io(e) => write!(f, "{e}"),
             Self::ApiResponse(e) => write!(f, "{e}"),
+            Self::Configure(e) => write!(f, "{e}"),
             Self::Stringified(s) => write!(f, "{s}"),
         }
     }
@@ -27,6 +29,7 @@ impl Clone for ErrorSource {
     fn clone(&self) -> Self {
         match self {
             Self::ApiResponse(e) => Self::ApiResponse(e.clone()),
+            Self::Configure(e) => Self::Configure(e.clone()),
             other => Self::Stringified(other.to_string()),
         }
     }
@@ -39,6 +42,7 @@ impl Error for ErrorSource {
             Self::SerdeJson(e) => Some(e),
             Self::Io(e) => Some(e),
             Self::ApiResponse(e) => Some(e),
+            Self::Configure(e) => Some(e),
             Self::Stringified(_) => None,
         }
     }
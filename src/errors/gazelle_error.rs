@@ -54,6 +54,13 @@ impl GazelleError {
         }
     }
 
+    pub(crate) fn replay(source: IoError) -> Self {
+        Self {
+            operation: GazelleOperation::SendRequest,
+            source: ErrorSource::Io(source),
+        }
+    }
+
     pub(crate) fn deserialization(source: JsonError) -> Self {
         Self {
             operation: GazelleOperation::Deserialize,
@@ -68,10 +75,38 @@ impl GazelleError {
         }
     }
 
+    pub(crate) fn write_file(source: IoError) -> Self {
+        Self {
+            operation: GazelleOperation::WriteFile,
+            source: ErrorSource::Io(source),
+        }
+    }
+
+    pub(crate) fn cassette(source: IoError) -> Self {
+        Self {
+            operation: GazelleOperation::Cassette,
+            source: ErrorSource::Io(source),
+        }
+    }
+
+    pub(crate) fn configure(option: &str, message: impl Display) -> Self {
+        Self {
+            operation: GazelleOperation::Configure,
+            source: ErrorSource::Configure(ConfigureError {
+                option: option.to_owned(),
+                message: message.to_string(),
+            }),
+        }
+    }
+
     pub(crate) fn api_response(kind: ApiResponseKind, message: String, status: u16) -> Self {
         Self {
             operation: GazelleOperation::ApiResponse(kind),
-            source: ErrorSource::ApiResponse(ApiResponseError { message, status }),
+            source: ErrorSource::ApiResponse(ApiResponseError {
+                message,
+                status,
+                retry_after: None,
+            }),
         }
     }
 
@@ -91,18 +126,72 @@ impl GazelleError {
         Self::api_response(ApiResponseKind::TooManyRequests, message, status)
     }
 
+    pub(crate) fn bad_gateway(message: String, status: u16) -> Self {
+        Self::api_response(ApiResponseKind::BadGateway, message, status)
+    }
+
+    pub(crate) fn service_unavailable(message: String, status: u16) -> Self {
+        Self::api_response(ApiResponseKind::ServiceUnavailable, message, status)
+    }
+
+    pub(crate) fn gateway_timeout(message: String, status: u16) -> Self {
+        Self::api_response(ApiResponseKind::GatewayTimeout, message, status)
+    }
+
+    pub(crate) fn request_already_filled(message: String, status: u16) -> Self {
+        Self::api_response(ApiResponseKind::RequestAlreadyFilled, message, status)
+    }
+
+    pub(crate) fn request_mismatch(message: String, status: u16) -> Self {
+        Self::api_response(ApiResponseKind::RequestMismatch, message, status)
+    }
+
     pub(crate) fn other(message: String, status: u16) -> Self {
         Self::api_response(ApiResponseKind::Other, message, status)
     }
 
-    /// Whether the error is a transient rate-limit that warrants retry.
+    /// Attach the delay requested by a `Retry-After` header.
     ///
-    /// - Returns `false` for non-API-response operations
+    /// - Ignored for non-API-response sources
+    pub(crate) fn with_retry_after(mut self, retry_after: Option<Duration>) -> Self {
+        if let ErrorSource::ApiResponse(error) = &mut self.source {
+            error.retry_after = retry_after;
+        }
+        self
+    }
+
+    /// Delay requested by the indexer before retrying, if any.
+    #[must_use]
+    pub fn retry_after(&self) -> Option<Duration> {
+        match &self.source {
+            ErrorSource::ApiResponse(error) => error.retry_after,
+            _ => None,
+        }
+    }
+
+    /// Whether the error is a transient failure that warrants retry.
+    ///
+    /// See [`retry_class`](Self::retry_class).
     #[must_use]
     pub fn is_retryable(&self) -> bool {
-        match self.operation {
-            GazelleOperation::ApiResponse(kind) => kind.is_retryable(),
-            _ => false,
+        self.retry_class().is_some()
+    }
+
+    /// Get the [`RetryClass`] if the error is a transient failure.
+    ///
+    /// - API responses are classified by [`ApiResponseKind::retry_class`]
+    /// - Connection failures, timeouts and interrupted response bodies are
+    ///   [`RetryClass::Transport`]
+    /// - Returns `None` for everything else
+    #[must_use]
+    pub fn retry_class(&self) -> Option<RetryClass> {
+        match (self.operation, &self.source) {
+            (GazelleOperation::ApiResponse(kind), _) => kind.retry_class(),
+            (
+                GazelleOperation::SendRequest | GazelleOperation::ReadResponse,
+                ErrorSource::Reqwest(error),
+            ) if is_transient(error) => Some(RetryClass::Transport),
+            _ => None,
         }
     }
 
@@ -117,9 +206,10 @@ impl GazelleError {
         }
     }
 
-    /// Get a [`GazelleError`] if the status code indicates a known client error.
+    /// Get a [`GazelleError`] if the status code indicates a known client or gateway error.
     ///
-    /// *RED only as OPS returns `200 Success` for everything*
+    /// - Client errors are *RED only* as OPS returns `200 Success` for everything
+    /// - Gateway errors come from the server or a proxy in front of it
     pub(crate) fn match_status_error(
         status_code: StatusCode,
         message: Option<String>,
@@ -131,32 +221,59 @@ impl GazelleError {
             StatusCode::UNAUTHORIZED => Some(Self::unauthorized(message, status)),
             StatusCode::NOT_FOUND => Some(Self::not_found(message, status)),
             StatusCode::TOO_MANY_REQUESTS => Some(Self::too_many_requests(message, status)),
+            StatusCode::BAD_GATEWAY => Some(Self::bad_gateway(message, status)),
+            StatusCode::SERVICE_UNAVAILABLE => Some(Self::service_unavailable(message, status)),
+            StatusCode::GATEWAY_TIMEOUT => Some(Self::gateway_timeout(message, status)),
             _ => None,
         }
     }
 
     /// Get a [`GazelleError`] if the response error string indicates a known client error.
-    pub(crate) fn match_response_error(error: &str, status: u16) -> Option<Self> {
+    ///
+    /// - `action` is the `action` query parameter of the request
+    /// - Request fill failures are only matched for the `requestfill` action
+    pub(crate) fn match_response_error(
+        action: Option<&str>,
+        error: &str,
+        status: u16,
+    ) -> Option<Self> {
         let message = error.to_owned();
-        match error {
-            "bad id parameter" | "bad parameters" | "no such user" => {
+        match (action, error) {
+            (_, "bad id parameter" | "bad parameters" | "no such user") => {
                 Some(Self::bad_request(message, status))
             }
-            "This page is limited to API key usage only." | "This page requires an api token" => {
-                Some(Self::unauthorized(message, status))
-            }
-            "endpoint not found" | "failure" | "could not find torrent" => {
+            (
+                _,
+                "This page is limited to API key usage only." | "This page requires an api token",
+            ) => Some(Self::unauthorized(message, status)),
+            (_, "endpoint not found" | "failure" | "could not find torrent") => {
                 Some(Self::not_found(message, status))
             }
-            "Rate limit exceeded" => Some(Self::too_many_requests(message, status)),
+            (_, "Rate limit exceeded") => Some(Self::too_many_requests(message, status)),
+            (Some("requestfill"), "This request has already been filled.") => {
+                Some(Self::request_already_filled(message, status))
+            }
+            (
+                Some("requestfill"),
+                "Not a matching torrent" | "The torrent does not match the request requirements",
+            ) => Some(Self::request_mismatch(message, status)),
             _ => None,
         }
     }
 }
 
+/// Whether a transport error is likely to succeed on retry.
+///
+/// - Errors building the request are not, as they fail the same way every time
+/// - Body and decode errors are when the connection drops while streaming the response
+fn is_transient(error: &ReqwestError) -> bool {
+    error.is_timeout() || error.is_connect() || error.is_body() || error.is_decode()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use reqwest::Client;
 
     #[test]
     fn match_status_error_bad_request() {
@@ -204,6 +321,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn match_status_error_gateway() {
+        for (status_code, kind) in [
+            (StatusCode::BAD_GATEWAY, ApiResponseKind::BadGateway),
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                ApiResponseKind::ServiceUnavailable,
+            ),
+            (StatusCode::GATEWAY_TIMEOUT, ApiResponseKind::GatewayTimeout),
+        ] {
+            let error = GazelleError::match_status_error(status_code, None)
+                .expect("gateway error should match");
+            assert_eq!(error.operation, GazelleOperation::ApiResponse(kind));
+            assert_eq!(error.retry_class(), Some(RetryClass::ServerError));
+        }
+    }
+
     #[test]
     fn match_status_error_success_returns_none() {
         let result = GazelleError::match_status_error(StatusCode::OK, None);
@@ -218,7 +352,7 @@ mod tests {
 
     #[test]
     fn match_response_error_bad_id() {
-        let result = GazelleError::match_response_error("bad id parameter", 200);
+        let result = GazelleError::match_response_error(None, "bad id parameter", 200);
         assert!(result.is_some());
         let error = result.expect("bad id should match");
         assert_eq!(
@@ -229,7 +363,7 @@ mod tests {
 
     #[test]
     fn match_response_error_bad_parameters() {
-        let result = GazelleError::match_response_error("bad parameters", 200);
+        let result = GazelleError::match_response_error(None, "bad parameters", 200);
         assert!(result.is_some());
         let error = result.expect("bad parameters should match");
         assert_eq!(
@@ -240,7 +374,7 @@ mod tests {
 
     #[test]
     fn match_response_error_no_such_user() {
-        let result = GazelleError::match_response_error("no such user", 200);
+        let result = GazelleError::match_response_error(None, "no such user", 200);
         assert!(result.is_some());
         let error = result.expect("no such user should match");
         assert_eq!(
@@ -251,8 +385,11 @@ mod tests {
 
     #[test]
     fn match_response_error_api_key_only() {
-        let result =
-            GazelleError::match_response_error("This page is limited to API key usage only.", 200);
+        let result = GazelleError::match_response_error(
+            None,
+            "This page is limited to API key usage only.",
+            200,
+        );
         assert!(result.is_some());
         let error = result.expect("api key only should match");
         assert_eq!(
@@ -263,7 +400,8 @@ mod tests {
 
     #[test]
     fn match_response_error_api_token_required() {
-        let result = GazelleError::match_response_error("This page requires an api token", 200);
+        let result =
+            GazelleError::match_response_error(None, "This page requires an api token", 200);
         assert!(result.is_some());
         let error = result.expect("api token required should match");
         assert_eq!(
@@ -274,7 +412,7 @@ mod tests {
 
     #[test]
     fn match_response_error_endpoint_not_found() {
-        let result = GazelleError::match_response_error("endpoint not found", 200);
+        let result = GazelleError::match_response_error(None, "endpoint not found", 200);
         assert!(result.is_some());
         let error = result.expect("endpoint not found should match");
         assert_eq!(
@@ -285,7 +423,7 @@ mod tests {
 
     #[test]
     fn match_response_error_failure() {
-        let result = GazelleError::match_response_error("failure", 200);
+        let result = GazelleError::match_response_error(None, "failure", 200);
         assert!(result.is_some());
         let error = result.expect("failure should match");
         assert_eq!(
@@ -296,7 +434,7 @@ mod tests {
 
     #[test]
     fn match_response_error_could_not_find_torrent() {
-        let result = GazelleError::match_response_error("could not find torrent", 200);
+        let result = GazelleError::match_response_error(None, "could not find torrent", 200);
         assert!(result.is_some());
         let error = result.expect("could not find torrent should match");
         assert_eq!(
@@ -307,7 +445,7 @@ mod tests {
 
     #[test]
     fn match_response_error_rate_limit() {
-        let result = GazelleError::match_response_error("Rate limit exceeded", 200);
+        let result = GazelleError::match_response_error(None, "Rate limit exceeded", 200);
         assert!(result.is_some());
         let error = result.expect("rate limit should match");
         assert_eq!(
@@ -316,15 +454,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn match_response_error_request_already_filled() {
+        let result = GazelleError::match_response_error(
+            Some("requestfill"),
+            "This request has already been filled.",
+            200,
+        );
+        let error = result.expect("already filled should match");
+        assert_eq!(
+            error.operation,
+            GazelleOperation::ApiResponse(ApiResponseKind::RequestAlreadyFilled)
+        );
+    }
+
+    #[test]
+    fn match_response_error_request_already_filled_other_wording() {
+        let result =
+            GazelleError::match_response_error(Some("requestfill"), "Request Already Filled", 400);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn match_response_error_request_already_filled_other_action() {
+        let result = GazelleError::match_response_error(
+            Some("torrent"),
+            "This request has already been filled.",
+            200,
+        );
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn match_response_error_request_mismatch() {
+        let result =
+            GazelleError::match_response_error(Some("requestfill"), "Not a matching torrent", 200);
+        let error = result.expect("mismatch should match");
+        assert_eq!(
+            error.operation,
+            GazelleOperation::ApiResponse(ApiResponseKind::RequestMismatch)
+        );
+    }
+
+    #[test]
+    fn match_response_error_request_mismatch_requirements() {
+        let result = GazelleError::match_response_error(
+            Some("requestfill"),
+            "The torrent does not match the request requirements",
+            400,
+        );
+        let error = result.expect("mismatch should match");
+        assert_eq!(
+            error.operation,
+            GazelleOperation::ApiResponse(ApiResponseKind::RequestMismatch)
+        );
+    }
+
+    #[test]
+    fn match_response_error_request_mismatch_without_action() {
+        let result = GazelleError::match_response_error(None, "Not a matching torrent", 200);
+        assert!(result.is_none());
+    }
+
     #[test]
     fn match_response_error_unknown_returns_none() {
-        let result = GazelleError::match_response_error("some unknown error message", 200);
+        let result = GazelleError::match_response_error(None, "some unknown error message", 200);
         assert!(result.is_none());
     }
 
     #[test]
     fn match_response_error_empty_returns_none() {
-        let result = GazelleError::match_response_error("", 200);
+        let result = GazelleError::match_response_error(None, "", 200);
         assert!(result.is_none());
     }
 
@@ -367,6 +567,64 @@ mod tests {
         assert!(!error.is_retryable());
     }
 
+    #[test]
+    fn retry_after_api_response() {
+        let error = GazelleError::too_many_requests("Rate limit exceeded".to_owned(), 429)
+            .with_retry_after(Some(Duration::from_secs(30)));
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn retry_after_send_request() {
+        let error = GazelleError {
+            operation: GazelleOperation::SendRequest,
+            source: ErrorSource::Io(IoError::other("network down")),
+        }
+        .with_retry_after(Some(Duration::from_secs(30)));
+        assert_eq!(error.retry_after(), None);
+    }
+
+    #[test]
+    fn retry_class_too_many_requests() {
+        let error = GazelleError::too_many_requests("Rate limit exceeded".to_owned(), 429);
+        assert_eq!(error.retry_class(), Some(RetryClass::RateLimited));
+    }
+
+    #[test]
+    fn retry_class_service_unavailable() {
+        let error = GazelleError::service_unavailable(String::new(), 503);
+        assert!(error.is_retryable());
+        assert_eq!(error.retry_class(), Some(RetryClass::ServerError));
+    }
+
+    #[test]
+    fn retry_class_deserialize() {
+        let error = GazelleError::deserialization(
+            json_from_str::<()>("invalid").expect_err("invalid json should fail"),
+        );
+        assert_eq!(error.retry_class(), None);
+    }
+
+    #[tokio::test]
+    async fn retry_class_connection_refused() {
+        // Arrange
+        let result = Client::new().get("http://127.0.0.1:1").send().await;
+        let error = GazelleError::request(result.expect_err("connection should be refused"));
+
+        // Act & Assert
+        assert_eq!(error.retry_class(), Some(RetryClass::Transport));
+    }
+
+    #[tokio::test]
+    async fn retry_class_invalid_request() {
+        // Arrange
+        let result = Client::new().get("not a url").send().await;
+        let error = GazelleError::request(result.expect_err("url should be invalid"));
+
+        // Act & Assert
+        assert_eq!(error.retry_class(), None);
+    }
+
     #[test]
     fn is_missing_not_found() {
         let error = GazelleError::not_found("nope".to_owned(), 404);
@@ -391,6 +649,13 @@ mod tests {
         assert!(!error.is_missing());
     }
 
+    #[test]
+    fn is_missing_request_already_filled() {
+        let error = GazelleError::request_already_filled("already filled".to_owned(), 200);
+        assert!(!error.is_missing());
+        assert!(!error.is_retryable());
+    }
+
     #[test]
     fn is_missing_other() {
         let error = GazelleError::other("boom".to_owned(), 500);
@@ -442,6 +707,13 @@ mod tests {
         assert_eq!(code, "gazelle_api::ReadFile");
     }
 
+    #[test]
+    fn diagnostic_code_write_file() {
+        let error = GazelleError::write_file(IoError::other("test"));
+        let code = error.code().expect("should have code").to_string();
+        assert_eq!(code, "gazelle_api::WriteFile");
+    }
+
     #[test]
     fn diagnostic_code_api_response_not_found() {
         let error = GazelleError::not_found("test".to_owned(), 404);
@@ -470,6 +742,13 @@ mod tests {
         assert_eq!(code, "gazelle_api::ApiResponse(TooManyRequests)");
     }
 
+    #[test]
+    fn diagnostic_code_api_response_request_mismatch() {
+        let error = GazelleError::request_mismatch("test".to_owned(), 200);
+        let code = error.code().expect("should have code").to_string();
+        assert_eq!(code, "gazelle_api::ApiResponse(RequestMismatch)");
+    }
+
     #[test]
     fn diagnostic_code_api_response_other() {
         let error = GazelleError::other("I'm a teapot".to_owned(), 418);
@@ -12,6 +12,12 @@ pub enum GazelleOperation {
     Deserialize,
     #[error("read file")]
     ReadFile,
+    #[error("write file")]
+    WriteFile,
+    #[error("configure client")]
+    Configure,
+    #[error("record response to cassette")]
+    Cassette,
     #[error("{0}")]
     ApiResponse(ApiResponseKind),
 }
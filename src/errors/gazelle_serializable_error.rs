@@ -24,6 +24,18 @@ pub enum GazelleSerializableError {
     ///
     /// Includes the `IoError` as a string.
     Upload { error: String },
+    /// An error occurred writing the downloaded torrent file.
+    ///
+    /// Includes the `IoError` as a string.
+    Download { error: String },
+    /// An option used to create the client is invalid.
+    ///
+    /// Includes the name of the option and why it is invalid.
+    Configure { option: String, message: String },
+    /// An error occurred writing a response to a cassette.
+    ///
+    /// Includes the `IoError` as a string.
+    Cassette { error: String },
     /// 400 Bad Request.
     ///
     /// Indicates that either the requested resource was not found,
@@ -38,6 +50,19 @@ pub enum GazelleSerializableError {
     /// 429 Too Many Request
     /// Indicates the rate limit has been hit
     TooManyRequests { message: String },
+    /// 502 Bad Gateway
+    /// Indicates the server or its proxy failed
+    BadGateway { message: String },
+    /// 503 Service Unavailable
+    /// Indicates the server is down or overloaded
+    ServiceUnavailable { message: String },
+    /// 504 Gateway Timeout
+    /// Indicates the server did not respond to its proxy in time
+    GatewayTimeout { message: String },
+    /// The request has already been filled
+    RequestAlreadyFilled { message: String },
+    /// The torrent does not satisfy the request
+    RequestMismatch { message: String },
     /// An unexpected status code and error message was received from the API
     /// Includes the `StatusCode` as a `u16` and
     /// the error message received from the API as a string
@@ -62,6 +87,20 @@ impl From<GazelleError> for GazelleSerializableError {
             (GazelleOperation::ReadFile, source) => Self::Upload {
                 error: source.to_string(),
             },
+            (GazelleOperation::WriteFile, source) => Self::Download {
+                error: source.to_string(),
+            },
+            (GazelleOperation::Configure, ErrorSource::Configure(error)) => Self::Configure {
+                option: error.option,
+                message: error.message,
+            },
+            (GazelleOperation::Configure, source) => Self::Configure {
+                option: String::new(),
+                message: source.to_string(),
+            },
+            (GazelleOperation::Cassette, source) => Self::Cassette {
+                error: source.to_string(),
+            },
             (GazelleOperation::ApiResponse(kind), ErrorSource::ApiResponse(api_err)) => {
                 match kind {
                     ApiResponseKind::BadRequest => Self::BadRequest {
@@ -76,6 +115,21 @@ impl From<GazelleError> for GazelleSerializableError {
                     ApiResponseKind::TooManyRequests => Self::TooManyRequests {
                         message: api_err.message,
                     },
+                    ApiResponseKind::BadGateway => Self::BadGateway {
+                        message: api_err.message,
+                    },
+                    ApiResponseKind::ServiceUnavailable => Self::ServiceUnavailable {
+                        message: api_err.message,
+                    },
+                    ApiResponseKind::GatewayTimeout => Self::GatewayTimeout {
+                        message: api_err.message,
+                    },
+                    ApiResponseKind::RequestAlreadyFilled => Self::RequestAlreadyFilled {
+                        message: api_err.message,
+                    },
+                    ApiResponseKind::RequestMismatch => Self::RequestMismatch {
+                        message: api_err.message,
+                    },
                     ApiResponseKind::Other => Self::Other {
                         status: api_err.status,
                         message: Some(api_err.message),
@@ -102,6 +156,18 @@ impl Display for GazelleSerializableError {
             Upload { error } => {
                 format!("{} to upload torrent file: {error}", "Failed")
             }
+            Download { error } => {
+                format!("{} to write torrent file: {error}", "Failed")
+            }
+            Configure { option, message } => {
+                format!(
+                    "{} to configure client: invalid {option}: {message}",
+                    "Failed"
+                )
+            }
+            Self::Cassette { error } => {
+                format!("{} to record response to cassette: {error}", "Failed")
+            }
             BadRequest { message } => {
                 format!("{} bad request response{}", "Received", append(message))
             }
@@ -118,6 +184,33 @@ impl Display for GazelleSerializableError {
                     append(message)
                 )
             }
+            BadGateway { message } => {
+                format!("{} bad gateway response{}", "Received", append(message))
+            }
+            ServiceUnavailable { message } => {
+                format!(
+                    "{} service unavailable response{}",
+                    "Received",
+                    append(message)
+                )
+            }
+            GatewayTimeout { message } => {
+                format!("{} gateway timeout response{}", "Received", append(message))
+            }
+            RequestAlreadyFilled { message } => {
+                format!(
+                    "{} request already filled response{}",
+                    "Received",
+                    append(message)
+                )
+            }
+            RequestMismatch { message } => {
+                format!(
+                    "{} torrent does not match request response{}",
+                    "Received",
+                    append(message)
+                )
+            }
             Other {
                 status,
                 message: error,
@@ -201,6 +294,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn conversion_to_serializable_download() {
+        let error = GazelleError::write_file(IoError::other("disk full"));
+        let serializable = GazelleSerializableError::from(error);
+        assert_eq!(
+            serializable.to_string(),
+            "Failed to write torrent file: disk full"
+        );
+        assert!(
+            matches!(serializable, GazelleSerializableError::Download { error } if error == "disk full")
+        );
+    }
+
+    #[test]
+    fn conversion_to_serializable_configure() {
+        let error = GazelleError::configure("url", "scheme must be http or https");
+        let serializable = GazelleSerializableError::from(error);
+        assert_eq!(
+            serializable.to_string(),
+            "Failed to configure client: invalid url: scheme must be http or https"
+        );
+        assert!(
+            matches!(serializable, GazelleSerializableError::Configure { option, .. } if option == "url")
+        );
+    }
+
     #[test]
     fn conversion_to_serializable_api_response() {
         let error = GazelleError::not_found("resource not found".to_owned(), 404);
@@ -210,6 +329,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn conversion_to_serializable_request_already_filled() {
+        let error = GazelleError::request_already_filled("already filled".to_owned(), 200);
+        let serializable = GazelleSerializableError::from(error);
+        assert_eq!(
+            serializable.to_string(),
+            "Received request already filled response: already filled"
+        );
+        assert!(
+            matches!(serializable, GazelleSerializableError::RequestAlreadyFilled { message } if message == "already filled")
+        );
+    }
+
+    #[test]
+    fn conversion_to_serializable_service_unavailable() {
+        let error = GazelleError::service_unavailable(String::new(), 503);
+        let serializable = GazelleSerializableError::from(error);
+        assert_eq!(
+            serializable.to_string(),
+            "Received service unavailable response"
+        );
+        assert!(matches!(
+            serializable,
+            GazelleSerializableError::ServiceUnavailable { .. }
+        ));
+    }
+
     #[test]
     fn conversion_to_serializable_other() {
         let error = GazelleError::other("unexpected".to_owned(), 500);
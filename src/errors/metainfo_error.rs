@@ -0,0 +1,22 @@
+use crate::prelude::*;
+
+/// An error parsing a `.torrent` file.
+#[derive(Clone, Debug, Eq, PartialEq, ThisError)]
+pub enum MetainfoError {
+    #[error("unexpected end of input at byte {0}")]
+    UnexpectedEnd(usize),
+    #[error("unexpected byte {byte:#04x} at byte {position}")]
+    UnexpectedByte { byte: u8, position: usize },
+    #[error("invalid integer at byte {0}")]
+    InvalidInteger(usize),
+    #[error("invalid string length at byte {0}")]
+    InvalidLength(usize),
+    #[error("nesting exceeds maximum depth at byte {0}")]
+    TooDeep(usize),
+    #[error("unexpected trailing data at byte {0}")]
+    TrailingData(usize),
+    #[error("missing field: {0}")]
+    MissingField(&'static str),
+    #[error("invalid field: {0}")]
+    InvalidField(&'static str),
+}
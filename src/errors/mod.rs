@@ -1,13 +1,19 @@
 mod api_response_error;
 mod api_response_kind;
+mod configure_error;
 mod error_source;
 mod gazelle_error;
 mod gazelle_operation;
 mod gazelle_serializable_error;
+mod metainfo_error;
+mod retry_class;
 
 pub use api_response_error::*;
 pub use api_response_kind::*;
+pub use configure_error::*;
 pub use error_source::*;
 pub use gazelle_error::*;
 pub use gazelle_operation::*;
 pub use gazelle_serializable_error::*;
+pub use metainfo_error::*;
+pub use retry_class::*;
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+
+/// Category of transient error that can be retried.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetryClass {
+    /// The API returned `TooManyRequests`
+    RateLimited,
+    /// The server or its proxy returned `502`, `503` or `504`
+    ServerError,
+    /// The connection failed, timed out, or was interrupted while reading the response
+    Transport,
+}
+
+impl RetryClass {
+    /// Every [`RetryClass`]
+    pub const ALL: [Self; 3] = [Self::RateLimited, Self::ServerError, Self::Transport];
+}
@@ -1,7 +1,9 @@
+use std::fs::read;
+use std::sync::Arc;
 use std::time::Duration;
 
 use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::{Client, ClientBuilder, header};
+use reqwest::{Certificate, Client, ClientBuilder, Proxy, Url, header};
 
 use crate::prelude::*;
 
@@ -12,54 +14,324 @@ const DEFAULT_LIMIT_DURATION: Duration = Duration::from_secs(10);
 pub struct GazelleClientFactory {
     /// Configuration options for the client
     pub options: GazelleClientOptions,
+    /// Rate limiter shared with other clients for the same account.
+    ///
+    /// The rate limit options are ignored if set. See [`RateLimiterRegistry`].
+    ///
+    /// Default: a new [`RateLimiter`] for this client only
+    pub limiter: Option<Arc<RateLimiter>>,
+    /// Hooks called for each request and response, see [`GazelleMiddleware`].
+    ///
+    /// Default: none
+    pub middleware: Vec<Arc<dyn GazelleMiddleware>>,
 }
 
 impl GazelleClientFactory {
     /// Create a new [`GazelleClient`] from the configured options
+    ///
+    /// # Panics
+    /// - If an option is invalid, see [`try_create`](Self::try_create)
     #[must_use]
     pub fn create(self) -> GazelleClient {
+        self.try_create().expect("client options should be valid")
+    }
+
+    /// Create a new [`GazelleClient`] from the configured options
+    ///
+    /// Returns a [`GazelleOperation::Configure`] error naming the option if:
+    /// - `url` is not an `http` or `https` URL with a host and no path, query or fragment
+    /// - `user_agent` or `key` can't be sent as a header, for example if it contains a newline
+    /// - `proxy` is not a valid URL
+    /// - A file in `root_certificates` can't be read or isn't valid PEM
+    pub fn try_create(self) -> Result<GazelleClient, GazelleError> {
+        let base_url = validate_url(&self.options.url)?;
+        let client = create_client(&self.options)?;
+        let limiter = self
+            .limiter
+            .unwrap_or_else(|| Arc::new(RateLimiter::from(&self.options)));
         let GazelleClientOptions {
-            user_agent,
-            key,
-            url: base_url,
-            requests_allowed_per_duration: num,
-            request_limit_duration: per,
+            user_agent: _,
+            key: _,
+            url: _,
+            requests_allowed_per_duration: _,
+            request_limit_duration: _,
+            rate_limit_path: _,
+            adaptive_rate: _,
             retry_delays,
+            retry_policy,
+            retry_uploads,
+            priority,
+            timeout: _,
+            connect_timeout: _,
+            read_timeout: _,
+            proxy: _,
+            root_certificates: _,
+            pool_idle_timeout: _,
+            pool_max_idle_per_host: _,
+            cassette,
         } = self.options;
-        let client = create_client(user_agent, key);
-        let limiter = RateLimiter::new(
-            num.unwrap_or(DEFAULT_LIMIT),
-            per.unwrap_or(DEFAULT_LIMIT_DURATION),
-        );
-        GazelleClient {
+        Ok(GazelleClient {
             base_url,
             client,
             limiter,
-            retry_delays,
+            retry_policy: retry_policy.unwrap_or_else(|| RetryPolicy::fixed(retry_delays)),
+            retry_uploads,
+            priority,
+            cassette,
+            middleware: self.middleware,
+        })
+    }
+}
+
+impl From<&GazelleClientOptions> for RateLimiter {
+    /// Create a [`RateLimiter`] from the rate limit options
+    fn from(options: &GazelleClientOptions) -> RateLimiter {
+        let store: Arc<dyn RateLimitStore> = match &options.rate_limit_path {
+            Some(path) => Arc::new(FileRateLimitStore::new(path.clone())),
+            None => Arc::new(MemoryRateLimitStore::default()),
+        };
+        let limiter = RateLimiter::with_store(
+            options
+                .requests_allowed_per_duration
+                .unwrap_or(DEFAULT_LIMIT),
+            options
+                .request_limit_duration
+                .unwrap_or(DEFAULT_LIMIT_DURATION),
+            store,
+        );
+        match options.adaptive_rate {
+            Some(adaptive) => limiter.with_adaptive(adaptive),
+            None => limiter,
         }
     }
 }
 
-fn create_client(user_agent: String, key: String) -> Client {
-    ClientBuilder::new()
-        .default_headers(get_headers(user_agent, key))
+/// Validate the base URL and remove any trailing slash.
+fn validate_url(url: &str) -> Result<String, GazelleError> {
+    let parsed = Url::parse(url).map_err(|error| GazelleError::configure("url", error))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(GazelleError::configure(
+            "url",
+            format!("scheme must be http or https: {url}"),
+        ));
+    }
+    if parsed.host_str().is_none_or(str::is_empty) {
+        return Err(GazelleError::configure(
+            "url",
+            format!("missing host: {url}"),
+        ));
+    }
+    if parsed.path() != "/" || parsed.query().is_some() || parsed.fragment().is_some() {
+        return Err(GazelleError::configure(
+            "url",
+            format!("must not have a path, query or fragment: {url}"),
+        ));
+    }
+    Ok(url.trim_end_matches('/').to_owned())
+}
+
+fn create_client(options: &GazelleClientOptions) -> Result<Client, GazelleError> {
+    let headers = get_headers(options.user_agent.clone(), options.key.clone())?;
+    let mut builder = ClientBuilder::new().default_headers(headers);
+    if let Some(timeout) = options.timeout {
+        builder = builder.timeout(timeout);
+    }
+    if let Some(timeout) = options.connect_timeout {
+        builder = builder.connect_timeout(timeout);
+    }
+    if let Some(timeout) = options.read_timeout {
+        builder = builder.read_timeout(timeout);
+    }
+    if let Some(proxy) = &options.proxy {
+        let proxy = Proxy::all(proxy).map_err(|error| GazelleError::configure("proxy", error))?;
+        builder = builder.proxy(proxy);
+    }
+    for path in &options.root_certificates {
+        builder = builder.tls_certs_merge(read_certificates(path)?);
+    }
+    if let Some(timeout) = options.pool_idle_timeout {
+        builder = builder.pool_idle_timeout(timeout);
+    }
+    if let Some(max) = options.pool_max_idle_per_host {
+        builder = builder.pool_max_idle_per_host(max);
+    }
+    builder
         .build()
-        .expect("Client builder should not fail")
+        .map_err(|error| GazelleError::configure("client", error))
+}
+
+fn read_certificates(path: &Path) -> Result<Vec<Certificate>, GazelleError> {
+    let describe = |error: &dyn Display| format!("{}: {error}", path.display());
+    let pem = read(path)
+        .map_err(|error| GazelleError::configure("root_certificates", describe(&error)))?;
+    Certificate::from_pem_bundle(&pem)
+        .map_err(|error| GazelleError::configure("root_certificates", describe(&error)))
 }
 
-fn get_headers(user_agent: String, key: String) -> HeaderMap {
+fn get_headers(user_agent: String, key: String) -> Result<HeaderMap, GazelleError> {
     let mut headers = HeaderMap::new();
-    headers.insert(
-        header::USER_AGENT,
-        HeaderValue::try_from(user_agent).expect("user agent should not fail"),
-    );
+    let user_agent = HeaderValue::try_from(user_agent)
+        .map_err(|error| GazelleError::configure("user_agent", error))?;
+    headers.insert(header::USER_AGENT, user_agent);
     headers.insert(header::ACCEPT, HeaderValue::from_static("application/json"));
-    headers.insert(header::AUTHORIZATION, get_authorization(key));
-    headers
+    headers.insert(header::AUTHORIZATION, get_authorization(key)?);
+    Ok(headers)
 }
 
-fn get_authorization(key: String) -> HeaderValue {
-    let mut value = HeaderValue::try_from(key).expect("Authorization header should not fail");
+/// Get the sensitive authorization header.
+///
+/// - The error never includes the key
+fn get_authorization(key: String) -> Result<HeaderValue, GazelleError> {
+    let mut value =
+        HeaderValue::try_from(key).map_err(|error| GazelleError::configure("key", error))?;
     value.set_sensitive(true);
-    value
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+    use tokio::net::TcpListener;
+
+    /// Bind a server that accepts connections but never responds.
+    async fn bind_hung_server() -> (TcpListener, String) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("should bind");
+        let address = listener.local_addr().expect("should have address");
+        (listener, format!("http://{address}"))
+    }
+
+    #[tokio::test]
+    async fn create_applies_timeout() {
+        // Arrange
+        let (_listener, url) = bind_hung_server().await;
+        let client = GazelleClientFactory {
+            options: GazelleClientOptions {
+                timeout: Some(Duration::from_millis(100)),
+                ..get_test_options(&url)
+            },
+            limiter: None,
+            middleware: Vec::new(),
+        }
+        .create();
+        let start = Instant::now();
+
+        // Act
+        let error = client.get_index().await.expect_err("should time out");
+
+        // Assert
+        assert_eq!(error.retry_class(), Some(RetryClass::Transport));
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn create_applies_proxy() {
+        // Arrange
+        let (_listener, url) = bind_hung_server().await;
+        let client = GazelleClientFactory {
+            options: GazelleClientOptions {
+                proxy: Some("socks5://127.0.0.1:1".to_owned()),
+                ..get_test_options(&url)
+            },
+            limiter: None,
+            middleware: Vec::new(),
+        }
+        .create();
+
+        // Act
+        let error = client
+            .get_index()
+            .await
+            .expect_err("proxy should refuse the connection");
+
+        // Assert
+        assert_eq!(error.retry_class(), Some(RetryClass::Transport));
+    }
+
+    fn try_create(options: GazelleClientOptions) -> Result<GazelleClient, GazelleError> {
+        GazelleClientFactory {
+            options,
+            limiter: None,
+            middleware: Vec::new(),
+        }
+        .try_create()
+    }
+
+    fn get_invalid_option(error: GazelleError) -> String {
+        assert_eq!(error.operation, GazelleOperation::Configure);
+        let ErrorSource::Configure(error) = error.source else {
+            unreachable!()
+        };
+        error.option
+    }
+
+    #[test]
+    fn try_create_trims_trailing_slash() {
+        let client = try_create(get_test_options("https://example.com/")).expect("should create");
+        assert_eq!(client.base_url, "https://example.com");
+    }
+
+    #[test]
+    fn try_create_key_with_newline() {
+        // Arrange
+        let options = GazelleClientOptions {
+            key: "secret\n".to_owned(),
+            ..get_test_options("https://example.com")
+        };
+
+        // Act
+        let error = try_create(options).err().expect("should be an error");
+
+        // Assert
+        assert!(!error.source.to_string().contains("secret"));
+        assert_eq!(get_invalid_option(error), "key");
+    }
+
+    #[test]
+    fn try_create_user_agent_with_newline() {
+        let options = GazelleClientOptions {
+            user_agent: "agent\r\n".to_owned(),
+            ..get_test_options("https://example.com")
+        };
+        let error = try_create(options).err().expect("should be an error");
+        assert_eq!(get_invalid_option(error), "user_agent");
+    }
+
+    #[test]
+    fn try_create_invalid_urls() {
+        for url in [
+            "example.com",
+            "ftp://example.com",
+            "https://example.com/ajax.php",
+            "https://example.com?id=1",
+            "https://example.com#top",
+            "file:///tmp",
+        ] {
+            let error = try_create(get_test_options(url))
+                .err()
+                .expect("should be an error");
+            assert_eq!(get_invalid_option(error), "url", "{url} should be invalid");
+        }
+    }
+
+    #[test]
+    fn try_create_invalid_proxy() {
+        let options = GazelleClientOptions {
+            proxy: Some("not a url".to_owned()),
+            ..get_test_options("https://example.com")
+        };
+        let error = try_create(options).err().expect("should be an error");
+        assert_eq!(get_invalid_option(error), "proxy");
+    }
+
+    #[test]
+    fn try_create_missing_root_certificate() {
+        let options = GazelleClientOptions {
+            root_certificates: vec![PathBuf::from("/does/not/exist.pem")],
+            ..get_test_options("https://example.com")
+        };
+        let error = try_create(options).err().expect("should be an error");
+        assert!(error.source.to_string().contains("/does/not/exist.pem"));
+        assert_eq!(get_invalid_option(error), "root_certificates");
+    }
 }
@@ -1,3 +1,7 @@
 pub(crate) use decode_entities::*;
+pub(crate) use pipe_list::*;
+pub(crate) use string_or_u32::*;
 
 mod decode_entities;
+mod pipe_list;
+mod string_or_u32;
@@ -0,0 +1,79 @@
+use crate::prelude::*;
+use serde::de::IntoDeserializer;
+use serde::de::value::StrDeserializer;
+
+/// Deserialize a list from either an array or a `|` separated string.
+///
+/// - Search results return `"Lossless|24bit Lossless"`
+/// - Detail responses return `["Lossless", "24bit Lossless"]`
+/// - `Any` and empty entries are dropped so an empty list means any value is accepted
+pub(crate) fn pipe_list<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Joined(String),
+        List(Vec<String>),
+    }
+
+    let entries = match Raw::deserialize(deserializer)? {
+        Raw::Joined(joined) => joined.split('|').map(str::to_owned).collect(),
+        Raw::List(list) => list,
+    };
+    entries
+        .iter()
+        .map(|entry| entry.trim())
+        .filter(|entry| !entry.is_empty() && *entry != "Any")
+        .map(|entry| {
+            let deserializer: StrDeserializer<'_, D::Error> = entry.into_deserializer();
+            T::deserialize(deserializer)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Wrapper {
+        #[serde(deserialize_with = "pipe_list")]
+        value: Vec<Quality>,
+    }
+
+    fn parse(json: &str) -> Vec<Quality> {
+        json_from_str::<Wrapper>(json)
+            .expect("test JSON should deserialize")
+            .value
+    }
+
+    #[test]
+    fn pipe_list_joined() {
+        assert_eq!(
+            parse(r#"{"value":"Lossless|24bit Lossless"}"#),
+            vec![Quality::Lossless, Quality::Lossless24]
+        );
+    }
+
+    #[test]
+    fn pipe_list_array() {
+        assert_eq!(
+            parse(r#"{"value":["V0 (VBR)","320"]}"#),
+            vec![Quality::V0, Quality::_320]
+        );
+    }
+
+    #[test]
+    fn pipe_list_any_is_empty() {
+        assert!(parse(r#"{"value":"Any"}"#).is_empty());
+        assert!(parse(r#"{"value":["Any"]}"#).is_empty());
+    }
+
+    #[test]
+    fn pipe_list_empty_string() {
+        assert!(parse(r#"{"value":""}"#).is_empty());
+    }
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+
+/// Deserialize a `u32` from either a number or a string.
+///
+/// - OPS browse responses return some IDs as strings
+/// - RED user torrents responses return every ID as a string
+pub(crate) fn string_or_u32<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    struct StringOrU32Visitor;
+
+    impl Visitor<'_> for StringOrU32Visitor {
+        type Value = u32;
+
+        fn expecting(&self, f: &mut Formatter) -> FmtResult {
+            f.write_str("a u32 or a string containing a u32")
+        }
+
+        fn visit_u64<E: DeError>(self, value: u64) -> Result<Self::Value, E> {
+            u32::try_from(value).map_err(DeError::custom)
+        }
+
+        fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+            value.parse().map_err(DeError::custom)
+        }
+    }
+
+    deserializer.deserialize_any(StringOrU32Visitor)
+}
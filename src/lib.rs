@@ -1,16 +1,31 @@
 mod actions;
+mod adaptive_rate;
+mod cassette;
 mod client;
 mod client_trait;
+mod clock;
+mod cross_seed;
 mod errors;
 mod factory;
 mod helpers;
+mod metainfo;
+mod middleware;
 #[cfg(feature = "mock")]
 mod mock;
+#[cfg(feature = "mock")]
+mod mock_server;
 mod options;
 pub mod prelude;
 mod rate;
+mod rate_limit_store;
 mod rate_limiter;
+mod rate_limiter_registry;
+mod rate_limiter_stats;
+mod request_priority;
+mod retry_policy;
 mod schema;
+#[cfg(feature = "tracing")]
+mod telemetry;
 #[cfg(test)]
 mod tests;
 
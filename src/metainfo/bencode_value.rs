@@ -0,0 +1,430 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::str::from_utf8;
+
+/// Maximum nesting of lists and dictionaries accepted by the decoder.
+const MAX_DEPTH: usize = 64;
+
+/// A bencoded value.
+///
+/// <https://www.bittorrent.org/beps/bep_0003.html#bencoding>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BencodeValue {
+    /// Integer
+    Integer(i64),
+    /// Byte string
+    ///
+    /// Not necessarily valid UTF-8.
+    Bytes(Vec<u8>),
+    /// List
+    List(Vec<BencodeValue>),
+    /// Dictionary
+    ///
+    /// Keys are sorted so encoding is canonical.
+    Dictionary(BTreeMap<Vec<u8>, BencodeValue>),
+}
+
+impl BencodeValue {
+    /// Decode a single bencoded value.
+    ///
+    /// - Returns an error if there is data after the value
+    pub fn decode(bytes: &[u8]) -> Result<Self, MetainfoError> {
+        let mut decoder = Decoder::new(bytes, None);
+        let value = decoder.value()?;
+        decoder.finish()?;
+        Ok(value)
+    }
+
+    /// Encode the value.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        self.encode_to(&mut output);
+        output
+    }
+
+    fn encode_to(&self, output: &mut Vec<u8>) {
+        match self {
+            Self::Integer(value) => {
+                output.push(b'i');
+                output.extend_from_slice(value.to_string().as_bytes());
+                output.push(b'e');
+            }
+            Self::Bytes(value) => encode_bytes(value, output),
+            Self::List(values) => {
+                output.push(b'l');
+                for value in values {
+                    value.encode_to(output);
+                }
+                output.push(b'e');
+            }
+            Self::Dictionary(entries) => {
+                output.push(b'd');
+                for (key, value) in entries {
+                    encode_bytes(key, output);
+                    value.encode_to(output);
+                }
+                output.push(b'e');
+            }
+        }
+    }
+
+    /// Get the value if this is an integer.
+    #[must_use]
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            Self::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Get the value if this is a byte string.
+    #[must_use]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Get the value if this is a valid UTF-8 byte string.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        self.as_bytes().and_then(|value| from_utf8(value).ok())
+    }
+
+    /// Get the values if this is a list.
+    #[must_use]
+    pub fn as_list(&self) -> Option<&[BencodeValue]> {
+        match self {
+            Self::List(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Get the entries if this is a dictionary.
+    #[must_use]
+    pub fn as_dictionary(&self) -> Option<&BTreeMap<Vec<u8>, BencodeValue>> {
+        match self {
+            Self::Dictionary(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    /// Get a dictionary entry by key.
+    ///
+    /// - `None` if this is not a dictionary or the key is absent
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&BencodeValue> {
+        self.as_dictionary()?.get(key.as_bytes())
+    }
+}
+
+/// Decode a bencoded dictionary and capture the raw bytes of one of its top level values.
+///
+/// Used to hash the `info` dictionary exactly as it appears in the file,
+/// even if the file is not canonically encoded.
+pub(crate) fn decode_with_raw<'a>(
+    bytes: &'a [u8],
+    key: &[u8],
+) -> Result<(BencodeValue, Option<&'a [u8]>), MetainfoError> {
+    let mut decoder = Decoder::new(bytes, Some(key));
+    let value = decoder.value()?;
+    decoder.finish()?;
+    let raw = decoder.captured.and_then(|range| bytes.get(range));
+    Ok((value, raw))
+}
+
+fn encode_bytes(value: &[u8], output: &mut Vec<u8>) {
+    output.extend_from_slice(value.len().to_string().as_bytes());
+    output.push(b':');
+    output.extend_from_slice(value);
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    depth: usize,
+    capture_key: Option<&'a [u8]>,
+    captured: Option<Range<usize>>,
+}
+
+impl<'a> Decoder<'a> {
+    fn new(bytes: &'a [u8], capture_key: Option<&'a [u8]>) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            depth: 0,
+            capture_key,
+            captured: None,
+        }
+    }
+
+    fn finish(&self) -> Result<(), MetainfoError> {
+        if self.position < self.bytes.len() {
+            return Err(MetainfoError::TrailingData(self.position));
+        }
+        Ok(())
+    }
+
+    fn peek(&self) -> Result<u8, MetainfoError> {
+        self.bytes
+            .get(self.position)
+            .copied()
+            .ok_or(MetainfoError::UnexpectedEnd(self.position))
+    }
+
+    fn next(&mut self) -> Result<u8, MetainfoError> {
+        let byte = self.peek()?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), MetainfoError> {
+        let position = self.position;
+        let byte = self.next()?;
+        if byte == expected {
+            Ok(())
+        } else {
+            Err(MetainfoError::UnexpectedByte { byte, position })
+        }
+    }
+
+    fn value(&mut self) -> Result<BencodeValue, MetainfoError> {
+        match self.peek()? {
+            b'i' => self.integer().map(BencodeValue::Integer),
+            b'l' => self.list(),
+            b'd' => self.dictionary(),
+            b'0'..=b'9' => self
+                .bytes()
+                .map(|value| BencodeValue::Bytes(value.to_vec())),
+            byte => Err(MetainfoError::UnexpectedByte {
+                byte,
+                position: self.position,
+            }),
+        }
+    }
+
+    /// Read ASCII up to, but not including, `terminator`.
+    fn read_until(&mut self, terminator: u8) -> Result<&'a str, MetainfoError> {
+        let start = self.position;
+        while self.peek()? != terminator {
+            self.position += 1;
+        }
+        let digits = self
+            .bytes
+            .get(start..self.position)
+            .ok_or(MetainfoError::UnexpectedEnd(start))?;
+        self.position += 1;
+        from_utf8(digits).map_err(|_| MetainfoError::InvalidInteger(start))
+    }
+
+    fn integer(&mut self) -> Result<i64, MetainfoError> {
+        self.expect(b'i')?;
+        let start = self.position;
+        let digits = self.read_until(b'e')?;
+        let unsigned = digits.strip_prefix('-').unwrap_or(digits);
+        if unsigned.is_empty() || !unsigned.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(MetainfoError::InvalidInteger(start));
+        }
+        digits
+            .parse()
+            .map_err(|_| MetainfoError::InvalidInteger(start))
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], MetainfoError> {
+        let start = self.position;
+        let digits = self.read_until(b':')?;
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(MetainfoError::InvalidLength(start));
+        }
+        let length: usize = digits
+            .parse()
+            .map_err(|_| MetainfoError::InvalidLength(start))?;
+        let end = self
+            .position
+            .checked_add(length)
+            .ok_or(MetainfoError::InvalidLength(start))?;
+        let value = self
+            .bytes
+            .get(self.position..end)
+            .ok_or(MetainfoError::UnexpectedEnd(self.bytes.len()))?;
+        self.position = end;
+        Ok(value)
+    }
+
+    fn enter(&mut self) -> Result<(), MetainfoError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(MetainfoError::TooDeep(self.position));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn list(&mut self) -> Result<BencodeValue, MetainfoError> {
+        self.enter()?;
+        self.expect(b'l')?;
+        let mut values = Vec::new();
+        while self.peek()? != b'e' {
+            values.push(self.value()?);
+        }
+        self.position += 1;
+        self.depth -= 1;
+        Ok(BencodeValue::List(values))
+    }
+
+    fn dictionary(&mut self) -> Result<BencodeValue, MetainfoError> {
+        self.enter()?;
+        self.expect(b'd')?;
+        let is_top_level = self.depth == 1;
+        let mut entries = BTreeMap::new();
+        while self.peek()? != b'e' {
+            let key = self.bytes()?.to_vec();
+            let start = self.position;
+            let value = self.value()?;
+            if is_top_level && self.capture_key == Some(key.as_slice()) {
+                self.captured = Some(start..self.position);
+            }
+            entries.insert(key, value);
+        }
+        self.position += 1;
+        self.depth -= 1;
+        Ok(BencodeValue::Dictionary(entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_integer() {
+        let output = BencodeValue::decode(b"i-42e").expect("should decode");
+        assert_eq!(output, BencodeValue::Integer(-42));
+    }
+
+    #[test]
+    fn decode_bytes() {
+        let output = BencodeValue::decode(b"4:spam").expect("should decode");
+        assert_eq!(output.as_str(), Some("spam"));
+    }
+
+    #[test]
+    fn decode_empty_bytes() {
+        let output = BencodeValue::decode(b"0:").expect("should decode");
+        assert_eq!(output.as_bytes(), Some(&[][..]));
+    }
+
+    #[test]
+    fn decode_list() {
+        let output = BencodeValue::decode(b"l4:spami7ee").expect("should decode");
+        assert_eq!(
+            output,
+            BencodeValue::List(vec![
+                BencodeValue::Bytes(b"spam".to_vec()),
+                BencodeValue::Integer(7),
+            ])
+        );
+    }
+
+    #[test]
+    fn decode_dictionary() {
+        let output = BencodeValue::decode(b"d3:cow3:moo4:spaml1:a1:bee").expect("should decode");
+        assert_eq!(
+            output.get("cow").and_then(BencodeValue::as_str),
+            Some("moo")
+        );
+        assert_eq!(
+            output
+                .get("spam")
+                .and_then(BencodeValue::as_list)
+                .map(<[BencodeValue]>::len),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn decode_unexpected_end() {
+        let error = BencodeValue::decode(b"l4:spam").expect_err("should fail");
+        assert_eq!(error, MetainfoError::UnexpectedEnd(7));
+    }
+
+    #[test]
+    fn decode_truncated_bytes() {
+        let error = BencodeValue::decode(b"10:spam").expect_err("should fail");
+        assert_eq!(error, MetainfoError::UnexpectedEnd(7));
+    }
+
+    #[test]
+    fn decode_invalid_integer() {
+        let error = BencodeValue::decode(b"i4x2e").expect_err("should fail");
+        assert_eq!(error, MetainfoError::InvalidInteger(1));
+        let error = BencodeValue::decode(b"ie").expect_err("should fail");
+        assert_eq!(error, MetainfoError::InvalidInteger(1));
+    }
+
+    #[test]
+    fn decode_invalid_length() {
+        let error = BencodeValue::decode(b"-1:a").expect_err("should fail");
+        assert_eq!(
+            error,
+            MetainfoError::UnexpectedByte {
+                byte: b'-',
+                position: 0
+            }
+        );
+    }
+
+    #[test]
+    fn decode_trailing_data() {
+        let error = BencodeValue::decode(b"i1ei2e").expect_err("should fail");
+        assert_eq!(error, MetainfoError::TrailingData(3));
+    }
+
+    #[test]
+    fn decode_too_deep() {
+        let mut input = vec![b'l'; MAX_DEPTH + 1];
+        input.extend(vec![b'e'; MAX_DEPTH + 1]);
+        let error = BencodeValue::decode(&input).expect_err("should fail");
+        assert_eq!(error, MetainfoError::TooDeep(MAX_DEPTH));
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        // Arrange
+        let input = b"d4:infod6:lengthi12e4:name5:a.txte4:listli-1e0:ee";
+
+        // Act
+        let value = BencodeValue::decode(input).expect("should decode");
+        let output = value.encode();
+
+        // Assert
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn encode_sorts_keys() {
+        let value = BencodeValue::decode(b"d1:bi1e1:ai2ee").expect("should decode");
+        assert_eq!(value.encode(), b"d1:ai2e1:bi1ee");
+    }
+
+    #[test]
+    fn decode_with_raw_captures_top_level_value() {
+        // Arrange - keys are deliberately unsorted
+        let input = b"d4:infod1:bi1e1:ai2ee4:name1:xe";
+
+        // Act
+        let (_, raw) = decode_with_raw(input, b"info").expect("should decode");
+
+        // Assert
+        assert_eq!(raw, Some(&b"d1:bi1e1:ai2ee"[..]));
+    }
+
+    #[test]
+    fn decode_with_raw_ignores_nested_keys() {
+        let input = b"d5:outerd4:infoi1eee";
+        let (_, raw) = decode_with_raw(input, b"info").expect("should decode");
+        assert_eq!(raw, None);
+    }
+}
@@ -0,0 +1,227 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+
+/// A difference between a [`Torrent`] and its `.torrent` metainfo.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MetainfoMismatch {
+    /// Torrent directory name differs from the metainfo name
+    DirectoryName {
+        /// `file_path` of the [`Torrent`]
+        expected: String,
+        /// `name` of the [`TorrentFile`]
+        actual: String,
+    },
+    /// Total size differs
+    TotalSize {
+        /// `size` of the [`Torrent`]
+        expected: u64,
+        /// Sum of the file sizes in the [`TorrentFile`]
+        actual: u64,
+    },
+    /// File is in the [`Torrent`] file list but not the metainfo
+    MissingFile(FileItem),
+    /// File is in the metainfo but not the [`Torrent`] file list
+    UnexpectedFile(FileItem),
+    /// File is in both but the size differs
+    FileSize {
+        /// Relative path of the file
+        name: String,
+        /// Size in the [`Torrent`] file list
+        expected: u64,
+        /// Size in the [`TorrentFile`]
+        actual: u64,
+    },
+}
+
+impl TorrentFile {
+    /// Compare the metainfo against the [`Torrent`] it was downloaded for.
+    ///
+    /// - Files are compared by relative path and size
+    /// - The directory name is only compared when `file_path` is set,
+    ///   Gazelle leaves it empty for single-file torrents
+    /// - Returns an empty vec when everything matches
+    ///
+    /// Requires a [`Torrent`] from the `torrent` or `torrentgroup` actions,
+    /// `artist` responses lack the file list.
+    #[must_use]
+    pub fn verify(&self, torrent: &Torrent) -> Vec<MetainfoMismatch> {
+        let mut mismatches = Vec::new();
+        let file_path = torrent.file_path.as_deref().unwrap_or_default();
+        if !file_path.is_empty() && file_path != self.name {
+            mismatches.push(MetainfoMismatch::DirectoryName {
+                expected: file_path.to_owned(),
+                actual: self.name.clone(),
+            });
+        }
+        let actual_size = self.total_size();
+        if torrent.size != actual_size {
+            mismatches.push(MetainfoMismatch::TotalSize {
+                expected: torrent.size,
+                actual: actual_size,
+            });
+        }
+        let mut actual: BTreeMap<String, u64> = self
+            .files
+            .iter()
+            .map(|file| (file.name.clone(), file.size))
+            .collect();
+        for file in torrent.get_files() {
+            match actual.remove(&file.name) {
+                None => mismatches.push(MetainfoMismatch::MissingFile(file)),
+                Some(size) if size != file.size => {
+                    mismatches.push(MetainfoMismatch::FileSize {
+                        name: file.name,
+                        expected: file.size,
+                        actual: size,
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        mismatches.extend(
+            actual
+                .into_iter()
+                .map(|(name, size)| MetainfoMismatch::UnexpectedFile(FileItem { name, size })),
+        );
+        mismatches
+    }
+}
+
+/// Parse a downloaded `.torrent` file and compare it against its [`Torrent`].
+///
+/// See [`TorrentFile::verify`].
+pub fn verify_metainfo(
+    torrent: &Torrent,
+    bytes: &[u8],
+) -> Result<Vec<MetainfoMismatch>, MetainfoError> {
+    Ok(TorrentFile::from_bytes(bytes)?.verify(torrent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METAINFO: &[u8] = b"d4:infod5:filesld6:lengthi100e4:pathl4:CD 19:01 a.flaceed6:lengthi50e4:pathl9:cover.jpgeee4:name5:Album12:piece lengthi32768e6:pieces0:ee";
+
+    fn torrent() -> Torrent {
+        Torrent {
+            size: 150,
+            file_list: Some("cover.jpg{{{50}}}|||CD 1/01 a.flac{{{100}}}".to_owned()),
+            file_path: Some("Album".to_owned()),
+            ..Torrent::default()
+        }
+    }
+
+    #[test]
+    fn verify_metainfo_match() {
+        // Arrange
+        let torrent = torrent();
+
+        // Act
+        let mismatches = verify_metainfo(&torrent, METAINFO).expect("should parse");
+
+        // Assert
+        assert!(mismatches.is_empty());
+    }
+
+    #[test]
+    fn verify_metainfo_directory_name() {
+        // Arrange
+        let torrent = Torrent {
+            file_path: Some("Other".to_owned()),
+            ..torrent()
+        };
+
+        // Act
+        let mismatches = verify_metainfo(&torrent, METAINFO).expect("should parse");
+
+        // Assert
+        assert_eq!(
+            mismatches,
+            vec![MetainfoMismatch::DirectoryName {
+                expected: "Other".to_owned(),
+                actual: "Album".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn verify_metainfo_skips_empty_directory_name() {
+        // Arrange
+        let torrent = Torrent {
+            file_path: None,
+            ..torrent()
+        };
+
+        // Act
+        let mismatches = verify_metainfo(&torrent, METAINFO).expect("should parse");
+
+        // Assert
+        assert!(mismatches.is_empty());
+    }
+
+    #[test]
+    fn verify_metainfo_files() {
+        // Arrange
+        let torrent = Torrent {
+            file_list: Some("CD 1/01 a.flac{{{99}}}|||folder.jpg{{{51}}}".to_owned()),
+            ..torrent()
+        };
+
+        // Act
+        let mismatches = verify_metainfo(&torrent, METAINFO).expect("should parse");
+
+        // Assert
+        assert_eq!(
+            mismatches,
+            vec![
+                MetainfoMismatch::FileSize {
+                    name: "CD 1/01 a.flac".to_owned(),
+                    expected: 99,
+                    actual: 100,
+                },
+                MetainfoMismatch::MissingFile(FileItem {
+                    name: "folder.jpg".to_owned(),
+                    size: 51,
+                }),
+                MetainfoMismatch::UnexpectedFile(FileItem {
+                    name: "cover.jpg".to_owned(),
+                    size: 50,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_metainfo_total_size() {
+        // Arrange
+        let torrent = Torrent {
+            size: 200,
+            ..torrent()
+        };
+
+        // Act
+        let mismatches = verify_metainfo(&torrent, METAINFO).expect("should parse");
+
+        // Assert
+        assert_eq!(
+            mismatches,
+            vec![MetainfoMismatch::TotalSize {
+                expected: 200,
+                actual: 150,
+            }]
+        );
+    }
+
+    #[test]
+    fn verify_metainfo_invalid() {
+        // Arrange
+        let torrent = torrent();
+
+        // Act
+        let error = verify_metainfo(&torrent, b"d4:info").expect_err("should fail");
+
+        // Assert
+        assert_eq!(error, MetainfoError::UnexpectedEnd(7));
+    }
+}
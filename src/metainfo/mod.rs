@@ -0,0 +1,7 @@
+pub use bencode_value::*;
+pub use metainfo_mismatch::*;
+pub use torrent_file::*;
+
+mod bencode_value;
+mod metainfo_mismatch;
+mod torrent_file;
@@ -0,0 +1,330 @@
+use crate::prelude::*;
+use sha1::{Digest, Sha1};
+use std::fmt::Write;
+
+/// A parsed `.torrent` metainfo file.
+///
+/// <https://www.bittorrent.org/beps/bep_0003.html#metainfo-files>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TorrentFile {
+    /// Tracker announce URL
+    pub announce: Option<String>,
+    /// Tiers of tracker announce URLs
+    ///
+    /// <https://www.bittorrent.org/beps/bep_0012.html>
+    pub announce_list: Vec<Vec<String>>,
+    /// Comment
+    pub comment: Option<String>,
+    /// Name and version of the program that created the file
+    pub created_by: Option<String>,
+    /// Creation time as a unix timestamp
+    pub creation_date: Option<i64>,
+    /// Suggested name of the file or directory
+    ///
+    /// Equivalent to `file_path` of a multi-file [`Torrent`].
+    pub name: String,
+    /// Number of bytes in each piece
+    pub piece_length: u64,
+    /// Concatenated SHA-1 hashes of each piece
+    pub pieces: Vec<u8>,
+    /// Is the private flag set?
+    ///
+    /// <https://www.bittorrent.org/beps/bep_0027.html>
+    pub private: bool,
+    /// Source flag
+    ///
+    /// Set by the indexer to make the info hash unique, for example `OPS` or `RED`.
+    pub source: Option<String>,
+    /// Files in the order they appear in the torrent
+    ///
+    /// - Multi-file torrents have paths relative to [`name`](Self::name)
+    /// - Single-file torrents have one entry named [`name`](Self::name)
+    pub files: Vec<FileItem>,
+    /// Encoded info dictionary exactly as it appeared in the file
+    info: Vec<u8>,
+}
+
+impl TorrentFile {
+    /// Parse a `.torrent` file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MetainfoError> {
+        let (root, info_raw) = decode_with_raw(bytes, b"info")?;
+        if root.as_dictionary().is_none() {
+            return Err(MetainfoError::InvalidField("root"));
+        }
+        let info = root
+            .get("info")
+            .ok_or(MetainfoError::MissingField("info"))?;
+        if info.as_dictionary().is_none() {
+            return Err(MetainfoError::InvalidField("info"));
+        }
+        let info_raw = info_raw.ok_or(MetainfoError::MissingField("info"))?;
+        let name = get_lossy(info, "name").ok_or(MetainfoError::MissingField("name"))?;
+        let piece_length = info
+            .get("piece length")
+            .ok_or(MetainfoError::MissingField("piece length"))?
+            .as_integer()
+            .and_then(|value| u64::try_from(value).ok())
+            .ok_or(MetainfoError::InvalidField("piece length"))?;
+        let pieces = info
+            .get("pieces")
+            .ok_or(MetainfoError::MissingField("pieces"))?
+            .as_bytes()
+            .ok_or(MetainfoError::InvalidField("pieces"))?
+            .to_vec();
+        Ok(Self {
+            announce: get_lossy(&root, "announce"),
+            announce_list: get_announce_list(&root),
+            comment: get_lossy(&root, "comment"),
+            created_by: get_lossy(&root, "created by"),
+            creation_date: root.get("creation date").and_then(BencodeValue::as_integer),
+            files: get_files(info, &name)?,
+            name,
+            piece_length,
+            pieces,
+            private: info.get("private").and_then(BencodeValue::as_integer) == Some(1),
+            source: get_lossy(info, "source"),
+            info: info_raw.to_vec(),
+        })
+    }
+
+    /// SHA-1 hash of the encoded info dictionary.
+    #[must_use]
+    pub fn info_hash(&self) -> [u8; 20] {
+        Sha1::digest(&self.info).into()
+    }
+
+    /// SHA-1 hash of the encoded info dictionary as a lowercase hex string.
+    ///
+    /// Suitable for [`GazelleClient::get_torrent_by_hash`].
+    #[must_use]
+    pub fn info_hash_hex(&self) -> String {
+        self.info_hash()
+            .iter()
+            .fold(String::with_capacity(40), |mut output, byte| {
+                let _ = write!(output, "{byte:02x}");
+                output
+            })
+    }
+
+    /// Files sorted by name.
+    ///
+    /// Comparable with [`Torrent::get_files`].
+    #[must_use]
+    pub fn get_files(&self) -> Vec<FileItem> {
+        let mut files = self.files.clone();
+        files.sort_by(|a, b| a.name.cmp(&b.name));
+        files
+    }
+
+    /// Total size of all files in bytes.
+    ///
+    /// - Saturates at `u64::MAX` as the lengths in a malformed file can overflow
+    #[must_use]
+    pub fn total_size(&self) -> u64 {
+        self.files
+            .iter()
+            .fold(0, |total: u64, file| total.saturating_add(file.size))
+    }
+}
+
+fn get_lossy(value: &BencodeValue, key: &str) -> Option<String> {
+    value
+        .get(key)
+        .and_then(BencodeValue::as_bytes)
+        .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+}
+
+fn get_announce_list(root: &BencodeValue) -> Vec<Vec<String>> {
+    root.get("announce-list")
+        .and_then(BencodeValue::as_list)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(BencodeValue::as_list)
+        .map(|tier| {
+            tier.iter()
+                .filter_map(BencodeValue::as_bytes)
+                .map(|url| String::from_utf8_lossy(url).into_owned())
+                .collect()
+        })
+        .collect()
+}
+
+fn get_files(info: &BencodeValue, name: &str) -> Result<Vec<FileItem>, MetainfoError> {
+    let Some(files) = info.get("files") else {
+        let size = get_length(info)?;
+        return Ok(vec![FileItem {
+            name: name.to_owned(),
+            size,
+        }]);
+    };
+    files
+        .as_list()
+        .ok_or(MetainfoError::InvalidField("files"))?
+        .iter()
+        .map(|file| {
+            let size = get_length(file)?;
+            let segments = file
+                .get("path")
+                .ok_or(MetainfoError::MissingField("path"))?
+                .as_list()
+                .ok_or(MetainfoError::InvalidField("path"))?
+                .iter()
+                .map(|segment| {
+                    segment
+                        .as_bytes()
+                        .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+                        .ok_or(MetainfoError::InvalidField("path"))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(FileItem {
+                name: segments.join("/"),
+                size,
+            })
+        })
+        .collect()
+}
+
+fn get_length(value: &BencodeValue) -> Result<u64, MetainfoError> {
+    value
+        .get("length")
+        .ok_or(MetainfoError::MissingField("length"))?
+        .as_integer()
+        .and_then(|length| u64::try_from(length).ok())
+        .ok_or(MetainfoError::InvalidField("length"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn single_file() -> Vec<u8> {
+        let mut bytes = b"d8:announce28:https://example.com/announce10:created by4:test13:creation datei1700000000e4:info".to_vec();
+        bytes.extend_from_slice(SINGLE_FILE_INFO);
+        bytes.push(b'e');
+        bytes
+    }
+
+    const SINGLE_FILE_INFO: &[u8] = b"d6:lengthi12e4:name5:a.txt12:piece lengthi16384e6:pieces20:\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x007:privatei1e6:source3:OPSe";
+
+    fn multi_file() -> Vec<u8> {
+        let mut bytes = b"d13:announce-listll6:tier1aee4:info".to_vec();
+        bytes.extend_from_slice(b"d5:filesld6:lengthi100e4:pathl4:CD 19:01 a.flaceed6:lengthi50e4:pathl9:cover.jpgeee4:name5:Album12:piece lengthi32768e6:pieces20:");
+        bytes.extend_from_slice(&[1; 20]);
+        bytes.extend_from_slice(b"ee");
+        bytes
+    }
+
+    #[test]
+    fn from_bytes_single_file() {
+        // Arrange & Act
+        let torrent = TorrentFile::from_bytes(&single_file()).expect("should parse");
+
+        // Assert
+        assert_eq!(
+            torrent.announce.as_deref(),
+            Some("https://example.com/announce")
+        );
+        assert_eq!(torrent.created_by.as_deref(), Some("test"));
+        assert_eq!(torrent.creation_date, Some(1_700_000_000));
+        assert_eq!(torrent.name, "a.txt");
+        assert_eq!(torrent.piece_length, 16384);
+        assert_eq!(torrent.pieces.len(), 20);
+        assert!(torrent.private);
+        assert_eq!(torrent.source.as_deref(), Some("OPS"));
+        assert_eq!(
+            torrent.files,
+            vec![FileItem {
+                name: "a.txt".to_owned(),
+                size: 12,
+            }]
+        );
+    }
+
+    #[test]
+    fn from_bytes_multi_file() {
+        // Arrange & Act
+        let torrent = TorrentFile::from_bytes(&multi_file()).expect("should parse");
+
+        // Assert
+        assert!(torrent.announce.is_none());
+        assert_eq!(torrent.announce_list, vec![vec!["tier1a".to_owned()]]);
+        assert_eq!(torrent.name, "Album");
+        assert!(!torrent.private);
+        assert!(torrent.source.is_none());
+        assert_eq!(torrent.total_size(), 150);
+        assert_eq!(
+            torrent.get_files(),
+            vec![
+                FileItem {
+                    name: "CD 1/01 a.flac".to_owned(),
+                    size: 100,
+                },
+                FileItem {
+                    name: "cover.jpg".to_owned(),
+                    size: 50,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn total_size_saturates() {
+        // Arrange
+        let mut torrent = TorrentFile::from_bytes(&single_file()).expect("should parse");
+        let file = FileItem {
+            name: "a.txt".to_owned(),
+            size: u64::MAX,
+        };
+        torrent.files = vec![file.clone(), file];
+
+        // Act
+        let output = torrent.total_size();
+
+        // Assert
+        assert_eq!(output, u64::MAX);
+    }
+
+    #[test]
+    fn info_hash_single_file() {
+        let torrent = TorrentFile::from_bytes(&single_file()).expect("should parse");
+        assert_eq!(
+            torrent.info_hash_hex(),
+            "150fdeb252060b499d59c8728e2ed4b4a776c109"
+        );
+    }
+
+    #[test]
+    fn info_hash_multi_file() {
+        let torrent = TorrentFile::from_bytes(&multi_file()).expect("should parse");
+        assert_eq!(
+            torrent.info_hash_hex(),
+            "fc76e89172a7a05e29f5734996090d0701aa3a23"
+        );
+    }
+
+    #[test]
+    fn from_bytes_missing_info() {
+        let error = TorrentFile::from_bytes(b"d8:announce1:xe").expect_err("should fail");
+        assert_eq!(error, MetainfoError::MissingField("info"));
+    }
+
+    #[test]
+    fn from_bytes_missing_length() {
+        let input = b"d4:infod4:name1:x12:piece lengthi1e6:pieces0:ee";
+        let error = TorrentFile::from_bytes(input).expect_err("should fail");
+        assert_eq!(error, MetainfoError::MissingField("length"));
+    }
+
+    #[test]
+    fn from_bytes_negative_length() {
+        let input = b"d4:infod6:lengthi-1e4:name1:x12:piece lengthi1e6:pieces0:ee";
+        let error = TorrentFile::from_bytes(input).expect_err("should fail");
+        assert_eq!(error, MetainfoError::InvalidField("length"));
+    }
+
+    #[test]
+    fn from_bytes_not_dictionary() {
+        let error = TorrentFile::from_bytes(b"le").expect_err("should fail");
+        assert_eq!(error, MetainfoError::InvalidField("root"));
+    }
+}
@@ -0,0 +1,132 @@
+use crate::prelude::*;
+
+/// Hooks called for each request a [`GazelleClient`] sends to the API.
+///
+/// Layers are registered on [`GazelleClientFactory::middleware`].
+///
+/// - Requests pass through the layers in order, responses in reverse order
+/// - Each retry attempt is a separate request
+/// - Hooks are called when a [`Cassette`] replays, but not while waiting for the rate limiter
+pub trait GazelleMiddleware: Send + Sync {
+    /// Called before the request is sent.
+    ///
+    /// Headers added to [`MiddlewareRequest::headers`] are sent with the request.
+    fn on_request(&self, _request: &mut MiddlewareRequest) {}
+
+    /// Called once the response is read and parsed, or the request failed.
+    fn on_response(&self, _request: &MiddlewareRequest, _response: &MiddlewareResponse<'_>) {}
+}
+
+#[cfg(all(test, feature = "mock"))]
+#[expect(
+    clippy::indexing_slicing,
+    reason = "test assertions on known fixture data"
+)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+    use std::sync::{Arc, Mutex};
+
+    /// Adds a header and records each hook as `{name} {event}`.
+    struct RecordingMiddleware {
+        name: &'static str,
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl GazelleMiddleware for RecordingMiddleware {
+        fn on_request(&self, request: &mut MiddlewareRequest) {
+            request
+                .headers
+                .insert("x-layer", HeaderValue::from_static(self.name));
+            self.push(format!("{} request {}", self.name, request.query));
+        }
+
+        fn on_response(&self, request: &MiddlewareRequest, response: &MiddlewareResponse<'_>) {
+            let status = response.status.map(|status| status.as_u16());
+            let error = response.error.map(|error| error.operation);
+            self.push(format!(
+                "{} response {:?} {status:?} {error:?}",
+                self.name,
+                request.action()
+            ));
+        }
+    }
+
+    impl RecordingMiddleware {
+        fn push(&self, event: String) {
+            self.events
+                .lock()
+                .expect("lock should not be poisoned")
+                .push(event);
+        }
+    }
+
+    fn create_client(
+        server: &MockGazelleServer,
+        events: &Arc<Mutex<Vec<String>>>,
+    ) -> GazelleClient {
+        let layer = |name| -> Arc<dyn GazelleMiddleware> {
+            Arc::new(RecordingMiddleware {
+                name,
+                events: events.clone(),
+            })
+        };
+        let middleware = vec![layer("outer"), layer("inner")];
+        GazelleClientFactory {
+            options: server.options(),
+            limiter: None,
+            middleware,
+        }
+        .create()
+    }
+
+    #[tokio::test]
+    async fn middleware_sees_requests_and_responses() {
+        // Arrange
+        let server = MockGazelleServer::start(MockIndexer::Red).expect("server should start");
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let client = create_client(&server, &events);
+
+        // Act
+        let _ = client.get_torrent(u32::MAX).await;
+
+        // Assert
+        let events = events.lock().expect("lock should not be poisoned").clone();
+        let query = format!("action=torrent&id={}", u32::MAX);
+        let error = Some(GazelleOperation::ApiResponse(ApiResponseKind::BadRequest));
+        assert_eq!(
+            events,
+            vec![
+                format!("outer request {query}"),
+                format!("inner request {query}"),
+                format!(r#"inner response Some("torrent") Some(400) {error:?}"#),
+                format!(r#"outer response Some("torrent") Some(400) {error:?}"#),
+            ]
+        );
+        let requests = server.requests();
+        assert_eq!(requests[0].get_header("x-layer"), Some("inner"));
+    }
+
+    #[tokio::test]
+    async fn middleware_sees_download() {
+        // Arrange
+        let server = MockGazelleServer::start(MockIndexer::Ops).expect("server should start");
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let client = create_client(&server, &events);
+
+        // Act
+        let bytes = client
+            .download_torrent(server.torrent_id())
+            .await
+            .expect("should download");
+
+        // Assert
+        let events = events.lock().expect("lock should not be poisoned").clone();
+        assert_eq!(events.len(), 4);
+        assert_eq!(
+            events[3],
+            r#"outer response Some("download") Some(200) None"#
+        );
+        assert!(!bytes.is_empty());
+    }
+}
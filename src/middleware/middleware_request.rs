@@ -0,0 +1,60 @@
+use crate::prelude::*;
+use reqwest::Method;
+use reqwest::header::HeaderMap;
+use std::time::Instant;
+
+/// Request seen by a [`GazelleMiddleware`].
+#[derive(Clone, Debug)]
+pub struct MiddlewareRequest {
+    /// HTTP method
+    pub method: Method,
+    /// Path of the URL.
+    ///
+    /// Example: `/ajax.php`
+    pub path: String,
+    /// Query string of the URL.
+    ///
+    /// Example: `action=torrent&id=123`
+    pub query: String,
+    /// Headers to send in addition to the client's default headers
+    pub headers: HeaderMap,
+    /// Time the request was sent
+    sent_at: Instant,
+}
+
+impl MiddlewareRequest {
+    pub(crate) fn new(method: Method, query: String) -> Self {
+        Self {
+            method,
+            path: "/ajax.php".to_owned(),
+            query,
+            headers: HeaderMap::new(),
+            sent_at: Instant::now(),
+        }
+    }
+
+    /// Value of a query parameter.
+    #[must_use]
+    pub fn get_param(&self, name: &str) -> Option<&str> {
+        self.query.split('&').find_map(|pair| {
+            let (key, value) = pair.split_once('=')?;
+            (key == name).then_some(value)
+        })
+    }
+
+    /// Action of the request.
+    #[must_use]
+    pub fn action(&self) -> Option<&str> {
+        self.get_param("action")
+    }
+
+    /// Duration since the request was sent.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.sent_at.elapsed()
+    }
+
+    pub(crate) fn mark_sent(&mut self) {
+        self.sent_at = Instant::now();
+    }
+}
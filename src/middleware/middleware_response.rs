@@ -0,0 +1,20 @@
+use crate::prelude::*;
+
+/// Response seen by a [`GazelleMiddleware`].
+#[derive(Debug)]
+pub struct MiddlewareResponse<'a> {
+    /// HTTP status code.
+    ///
+    /// `None` if no response was received.
+    pub status: Option<StatusCode>,
+    /// Time from sending the request until the response was read.
+    ///
+    /// - The body of a downloaded `.torrent` file is read after this
+    pub elapsed: Duration,
+    /// Size of the body in bytes.
+    ///
+    /// `None` if no response was received, or a download has no `Content-Length`.
+    pub body_size: Option<u64>,
+    /// Error parsed from the response, or the error sending the request.
+    pub error: Option<&'a GazelleError>,
+}
@@ -0,0 +1,7 @@
+pub use gazelle_middleware::*;
+pub use middleware_request::*;
+pub use middleware_response::*;
+
+mod gazelle_middleware;
+mod middleware_request;
+mod middleware_response;
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+
+/// Call made to a [`MockGazelleClient`] with its arguments.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MockCall {
+    /// `browse(request)`
+    Browse(BrowseRequest),
+    /// `get_torrent(id)`
+    GetTorrent(u32),
+    /// `get_torrent_by_hash(hash)`
+    GetTorrentByHash(String),
+    /// `get_torrent_group(id)`
+    GetTorrentGroup(u32),
+    /// `get_artist(artist)`
+    GetArtist(ArtistLookup),
+    /// `search_requests(search)`
+    SearchRequests(RequestSearch),
+    /// `get_request(id)`
+    GetRequest(u32),
+    /// `fill_request(request_id, torrent_id)`
+    FillRequest(u32, u32),
+    /// `get_user(id)`
+    GetUser(u32),
+    /// `get_user_torrents(user_id, kind, limit, offset)`
+    GetUserTorrents(u32, UserTorrentKind, u32, u32),
+    /// `get_index()`
+    GetIndex,
+    /// `download_torrent(id)`
+    DownloadTorrent(u32),
+    /// `download_torrent_to_path(id, path)`
+    DownloadTorrentToPath(u32, PathBuf),
+    /// `upload_torrent(upload)`
+    UploadTorrent(UploadForm),
+}
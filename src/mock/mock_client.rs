@@ -0,0 +1,810 @@
+use crate::prelude::*;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Mock client for testing without live API calls
+///
+/// Set return values using the builder pattern, then use as `dyn GazelleClientTrait`.
+///
+/// - `with_*` sets the result for any arguments
+/// - `on_*` adds a [`MockRule`] for matching arguments, which is checked first
+/// - Rules are checked in the order they were added and the first matching rule wins
+/// - A rule returns its results in order, then repeats its last result
+/// - Every call is recorded, see [`calls`](Self::calls)
+/// - Clones share their rules and recorded calls
+///
+/// # Panics
+/// - If no rule matches and no `with_*` result is set, a method panics with
+///   `MockGazelleClient: no <method> result for the arguments`
+#[derive(Clone, Debug)]
+pub struct MockGazelleClient {
+    browse: MockMethod<BrowseRequest, BrowseResponse>,
+    get_torrent: MockMethod<u32, TorrentResponse>,
+    get_torrent_by_hash: MockMethod<String, TorrentResponse>,
+    get_torrent_group: MockMethod<u32, GroupResponse>,
+    get_artist: MockMethod<ArtistLookup, ArtistResponse>,
+    search_requests: MockMethod<RequestSearch, RequestSearchResponse>,
+    get_request: MockMethod<u32, RequestDetail>,
+    fill_request: MockMethod<(u32, u32), FillRequestResponse>,
+    get_user: MockMethod<u32, User>,
+    get_user_torrents: MockMethod<(u32, UserTorrentKind, u32, u32), Vec<UserTorrent>>,
+    get_index: MockMethod<(), IndexResponse>,
+    download_torrent: MockMethod<u32, Vec<u8>>,
+    download_torrent_to_path: MockMethod<(u32, PathBuf), u64>,
+    upload_torrent: MockMethod<UploadForm, UploadResponse>,
+    calls: Arc<Mutex<Vec<MockCall>>>,
+}
+
+impl MockGazelleClient {
+    /// Create a new mock client with no configured return values
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            browse: MockMethod::new("MockGazelleClient: no browse result for the arguments"),
+            get_torrent: MockMethod::new(
+                "MockGazelleClient: no get_torrent result for the arguments",
+            ),
+            get_torrent_by_hash: MockMethod::new(
+                "MockGazelleClient: no get_torrent_by_hash result for the arguments",
+            ),
+            get_torrent_group: MockMethod::new(
+                "MockGazelleClient: no get_torrent_group result for the arguments",
+            ),
+            get_artist: MockMethod::new(
+                "MockGazelleClient: no get_artist result for the arguments",
+            ),
+            search_requests: MockMethod::new(
+                "MockGazelleClient: no search_requests result for the arguments",
+            ),
+            get_request: MockMethod::new(
+                "MockGazelleClient: no get_request result for the arguments",
+            ),
+            fill_request: MockMethod::new(
+                "MockGazelleClient: no fill_request result for the arguments",
+            ),
+            get_user: MockMethod::new("MockGazelleClient: no get_user result for the arguments"),
+            get_user_torrents: MockMethod::new(
+                "MockGazelleClient: no get_user_torrents result for the arguments",
+            ),
+            get_index: MockMethod::new("MockGazelleClient: no get_index result for the arguments"),
+            download_torrent: MockMethod::new(
+                "MockGazelleClient: no download_torrent result for the arguments",
+            ),
+            download_torrent_to_path: MockMethod::new(
+                "MockGazelleClient: no download_torrent_to_path result for the arguments",
+            ),
+            upload_torrent: MockMethod::new(
+                "MockGazelleClient: no upload_torrent result for the arguments",
+            ),
+            calls: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Configure the return value for `browse`
+    #[must_use]
+    pub fn with_browse(mut self, result: Result<BrowseResponse, GazelleError>) -> Self {
+        self.browse = self.browse.with_returns(result);
+        self
+    }
+
+    /// Add a rule for the arguments of `browse`
+    #[must_use]
+    pub fn on_browse(mut self, rule: MockRule<BrowseRequest, BrowseResponse>) -> Self {
+        self.browse = self.browse.with_rule(rule);
+        self
+    }
+
+    /// Configure the return value for `get_torrent`
+    #[must_use]
+    pub fn with_get_torrent(mut self, result: Result<TorrentResponse, GazelleError>) -> Self {
+        self.get_torrent = self.get_torrent.with_returns(result);
+        self
+    }
+
+    /// Add a rule for the arguments of `get_torrent`
+    #[must_use]
+    pub fn on_get_torrent(mut self, rule: MockRule<u32, TorrentResponse>) -> Self {
+        self.get_torrent = self.get_torrent.with_rule(rule);
+        self
+    }
+
+    /// Configure the return value for `get_torrent_by_hash`
+    #[must_use]
+    pub fn with_get_torrent_by_hash(
+        mut self,
+        result: Result<TorrentResponse, GazelleError>,
+    ) -> Self {
+        self.get_torrent_by_hash = self.get_torrent_by_hash.with_returns(result);
+        self
+    }
+
+    /// Add a rule for the arguments of `get_torrent_by_hash`
+    #[must_use]
+    pub fn on_get_torrent_by_hash(mut self, rule: MockRule<String, TorrentResponse>) -> Self {
+        self.get_torrent_by_hash = self.get_torrent_by_hash.with_rule(rule);
+        self
+    }
+
+    /// Configure the return value for `get_torrent_group`
+    #[must_use]
+    pub fn with_get_torrent_group(mut self, result: Result<GroupResponse, GazelleError>) -> Self {
+        self.get_torrent_group = self.get_torrent_group.with_returns(result);
+        self
+    }
+
+    /// Add a rule for the arguments of `get_torrent_group`
+    #[must_use]
+    pub fn on_get_torrent_group(mut self, rule: MockRule<u32, GroupResponse>) -> Self {
+        self.get_torrent_group = self.get_torrent_group.with_rule(rule);
+        self
+    }
+
+    /// Configure the return value for `get_artist`
+    #[must_use]
+    pub fn with_get_artist(mut self, result: Result<ArtistResponse, GazelleError>) -> Self {
+        self.get_artist = self.get_artist.with_returns(result);
+        self
+    }
+
+    /// Add a rule for the arguments of `get_artist`
+    #[must_use]
+    pub fn on_get_artist(mut self, rule: MockRule<ArtistLookup, ArtistResponse>) -> Self {
+        self.get_artist = self.get_artist.with_rule(rule);
+        self
+    }
+
+    /// Configure the return value for `search_requests`
+    #[must_use]
+    pub fn with_search_requests(
+        mut self,
+        result: Result<RequestSearchResponse, GazelleError>,
+    ) -> Self {
+        self.search_requests = self.search_requests.with_returns(result);
+        self
+    }
+
+    /// Add a rule for the arguments of `search_requests`
+    #[must_use]
+    pub fn on_search_requests(
+        mut self,
+        rule: MockRule<RequestSearch, RequestSearchResponse>,
+    ) -> Self {
+        self.search_requests = self.search_requests.with_rule(rule);
+        self
+    }
+
+    /// Configure the return value for `get_request`
+    #[must_use]
+    pub fn with_get_request(mut self, result: Result<RequestDetail, GazelleError>) -> Self {
+        self.get_request = self.get_request.with_returns(result);
+        self
+    }
+
+    /// Add a rule for the arguments of `get_request`
+    #[must_use]
+    pub fn on_get_request(mut self, rule: MockRule<u32, RequestDetail>) -> Self {
+        self.get_request = self.get_request.with_rule(rule);
+        self
+    }
+
+    /// Configure the return value for `fill_request`
+    #[must_use]
+    pub fn with_fill_request(mut self, result: Result<FillRequestResponse, GazelleError>) -> Self {
+        self.fill_request = self.fill_request.with_returns(result);
+        self
+    }
+
+    /// Add a rule for the arguments of `fill_request`
+    #[must_use]
+    pub fn on_fill_request(mut self, rule: MockRule<(u32, u32), FillRequestResponse>) -> Self {
+        self.fill_request = self.fill_request.with_rule(rule);
+        self
+    }
+
+    /// Configure the return value for `get_user`
+    #[must_use]
+    pub fn with_get_user(mut self, result: Result<User, GazelleError>) -> Self {
+        self.get_user = self.get_user.with_returns(result);
+        self
+    }
+
+    /// Add a rule for the arguments of `get_user`
+    #[must_use]
+    pub fn on_get_user(mut self, rule: MockRule<u32, User>) -> Self {
+        self.get_user = self.get_user.with_rule(rule);
+        self
+    }
+
+    /// Configure the return value for `get_user_torrents`
+    #[must_use]
+    pub fn with_get_user_torrents(
+        mut self,
+        result: Result<Vec<UserTorrent>, GazelleError>,
+    ) -> Self {
+        self.get_user_torrents = self.get_user_torrents.with_returns(result);
+        self
+    }
+
+    /// Add a rule for the arguments of `get_user_torrents`
+    #[must_use]
+    pub fn on_get_user_torrents(
+        mut self,
+        rule: MockRule<(u32, UserTorrentKind, u32, u32), Vec<UserTorrent>>,
+    ) -> Self {
+        self.get_user_torrents = self.get_user_torrents.with_rule(rule);
+        self
+    }
+
+    /// Configure the return value for `get_index`
+    #[must_use]
+    pub fn with_get_index(mut self, result: Result<IndexResponse, GazelleError>) -> Self {
+        self.get_index = self.get_index.with_returns(result);
+        self
+    }
+
+    /// Add a rule for the arguments of `get_index`
+    #[must_use]
+    pub fn on_get_index(mut self, rule: MockRule<(), IndexResponse>) -> Self {
+        self.get_index = self.get_index.with_rule(rule);
+        self
+    }
+
+    /// Configure the return value for `download_torrent`
+    #[must_use]
+    pub fn with_download_torrent(mut self, result: Result<Vec<u8>, GazelleError>) -> Self {
+        self.download_torrent = self.download_torrent.with_returns(result);
+        self
+    }
+
+    /// Add a rule for the arguments of `download_torrent`
+    #[must_use]
+    pub fn on_download_torrent(mut self, rule: MockRule<u32, Vec<u8>>) -> Self {
+        self.download_torrent = self.download_torrent.with_rule(rule);
+        self
+    }
+
+    /// Configure the return value for `download_torrent_to_path`
+    ///
+    /// - No file is written
+    #[must_use]
+    pub fn with_download_torrent_to_path(mut self, result: Result<u64, GazelleError>) -> Self {
+        self.download_torrent_to_path = self.download_torrent_to_path.with_returns(result);
+        self
+    }
+
+    /// Add a rule for the arguments of `download_torrent_to_path`
+    #[must_use]
+    pub fn on_download_torrent_to_path(mut self, rule: MockRule<(u32, PathBuf), u64>) -> Self {
+        self.download_torrent_to_path = self.download_torrent_to_path.with_rule(rule);
+        self
+    }
+
+    /// Configure the return value for `upload_torrent`
+    #[must_use]
+    pub fn with_upload_torrent(mut self, result: Result<UploadResponse, GazelleError>) -> Self {
+        self.upload_torrent = self.upload_torrent.with_returns(result);
+        self
+    }
+
+    /// Add a rule for the arguments of `upload_torrent`
+    #[must_use]
+    pub fn on_upload_torrent(mut self, rule: MockRule<UploadForm, UploadResponse>) -> Self {
+        self.upload_torrent = self.upload_torrent.with_rule(rule);
+        self
+    }
+
+    /// Calls made to the mock client, in order
+    #[must_use]
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.lock_calls().clone()
+    }
+
+    fn record(&self, call: MockCall) {
+        self.lock_calls().push(call);
+    }
+
+    fn lock_calls(&self) -> MutexGuard<'_, Vec<MockCall>> {
+        self.calls
+            .lock()
+            .expect("mock calls lock should not be poisoned")
+    }
+}
+
+impl Default for MockGazelleClient {
+    /// Create a mock client with all `Ok()` responses configured
+    fn default() -> Self {
+        Self::new()
+            .with_browse(Ok(BrowseResponse::mock()))
+            .with_get_torrent(Ok(TorrentResponse::mock()))
+            .with_get_torrent_by_hash(Ok(TorrentResponse::mock()))
+            .with_get_torrent_group(Ok(GroupResponse::mock()))
+            .with_get_artist(Ok(ArtistResponse::mock()))
+            .with_search_requests(Ok(RequestSearchResponse::mock()))
+            .with_get_request(Ok(RequestDetail::mock()))
+            .with_fill_request(Ok(FillRequestResponse::mock()))
+            .with_get_user(Ok(User::mock()))
+            .with_get_user_torrents(Ok(vec![UserTorrent::mock()]))
+            .with_get_index(Ok(IndexResponse::mock()))
+            .with_download_torrent(Ok(vec![0xd8, 0x3a, 0x00]))
+            .with_download_torrent_to_path(Ok(3))
+            .with_upload_torrent(Ok(UploadResponse::mock()))
+    }
+}
+
+#[async_trait]
+impl GazelleClientTrait for MockGazelleClient {
+    async fn browse(&self, request: &BrowseRequest) -> Result<BrowseResponse, GazelleError> {
+        self.record(MockCall::Browse(request.clone()));
+        self.browse.call(&request.clone())
+    }
+
+    async fn get_torrent(&self, id: u32) -> Result<TorrentResponse, GazelleError> {
+        self.record(MockCall::GetTorrent(id));
+        self.get_torrent.call(&id)
+    }
+
+    async fn get_torrent_by_hash(&self, hash: &str) -> Result<TorrentResponse, GazelleError> {
+        self.record(MockCall::GetTorrentByHash(hash.to_owned()));
+        self.get_torrent_by_hash.call(&hash.to_owned())
+    }
+
+    async fn get_torrent_group(&self, id: u32) -> Result<GroupResponse, GazelleError> {
+        self.record(MockCall::GetTorrentGroup(id));
+        self.get_torrent_group.call(&id)
+    }
+
+    async fn get_artist(&self, artist: ArtistLookup) -> Result<ArtistResponse, GazelleError> {
+        self.record(MockCall::GetArtist(artist.clone()));
+        self.get_artist.call(&artist)
+    }
+
+    async fn search_requests(
+        &self,
+        search: &RequestSearch,
+    ) -> Result<RequestSearchResponse, GazelleError> {
+        self.record(MockCall::SearchRequests(search.clone()));
+        self.search_requests.call(&search.clone())
+    }
+
+    async fn get_request(&self, id: u32) -> Result<RequestDetail, GazelleError> {
+        self.record(MockCall::GetRequest(id));
+        self.get_request.call(&id)
+    }
+
+    async fn fill_request(
+        &self,
+        request_id: u32,
+        torrent_id: u32,
+    ) -> Result<FillRequestResponse, GazelleError> {
+        self.record(MockCall::FillRequest(request_id, torrent_id));
+        self.fill_request.call(&(request_id, torrent_id))
+    }
+
+    async fn get_user(&self, id: u32) -> Result<User, GazelleError> {
+        self.record(MockCall::GetUser(id));
+        self.get_user.call(&id)
+    }
+
+    async fn get_user_torrents(
+        &self,
+        user_id: u32,
+        kind: UserTorrentKind,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<UserTorrent>, GazelleError> {
+        self.record(MockCall::GetUserTorrents(user_id, kind, limit, offset));
+        self.get_user_torrents.call(&(user_id, kind, limit, offset))
+    }
+
+    async fn get_index(&self) -> Result<IndexResponse, GazelleError> {
+        self.record(MockCall::GetIndex);
+        self.get_index.call(&())
+    }
+
+    async fn download_torrent(&self, id: u32) -> Result<Vec<u8>, GazelleError> {
+        self.record(MockCall::DownloadTorrent(id));
+        self.download_torrent.call(&id)
+    }
+
+    async fn download_torrent_to_path(&self, id: u32, path: &Path) -> Result<u64, GazelleError> {
+        self.record(MockCall::DownloadTorrentToPath(id, path.to_path_buf()));
+        self.download_torrent_to_path
+            .call(&(id, path.to_path_buf()))
+    }
+
+    async fn upload_torrent(&self, upload: UploadForm) -> Result<UploadResponse, GazelleError> {
+        self.record(MockCall::UploadTorrent(upload.clone()));
+        self.upload_torrent.call(&upload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::Mutex;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn mock_get_torrent_by_hash_returns_configured_value() {
+        // Arrange
+        let expected = TorrentResponse::mock();
+        let mock = MockGazelleClient::new().with_get_torrent_by_hash(Ok(expected.clone()));
+
+        // Act
+        let result = mock.get_torrent_by_hash("ABC123").await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(
+            result.expect("should be ok").torrent.id,
+            expected.torrent.id
+        );
+    }
+
+    #[tokio::test]
+    async fn mock_get_torrent_returns_configured_value() {
+        // Arrange
+        let expected = TorrentResponse::mock();
+        let mock = MockGazelleClient::new().with_get_torrent(Ok(expected.clone()));
+
+        // Act
+        let result = mock.get_torrent(123).await;
+
+        // Assert
+        assert!(result.is_ok());
+        let response = result.expect("should be ok");
+        assert_eq!(response.torrent.id, expected.torrent.id);
+    }
+
+    #[tokio::test]
+    async fn mock_get_torrent_returns_error() {
+        // Arrange
+        let mock = MockGazelleClient::new()
+            .with_get_torrent(Err(GazelleError::not_found("not found".to_owned(), 404)));
+
+        // Act
+        let result = mock.get_torrent(999).await;
+
+        // Assert
+        assert!(result.is_err());
+        let error = result.expect_err("should be an error");
+        assert_eq!(
+            error.operation,
+            GazelleOperation::ApiResponse(ApiResponseKind::NotFound)
+        );
+    }
+
+    #[tokio::test]
+    async fn mock_fill_request_returns_error() {
+        // Arrange
+        let mock = MockGazelleClient::new().with_fill_request(Err(
+            GazelleError::request_already_filled("already filled".to_owned(), 200),
+        ));
+
+        // Act
+        let result = mock.fill_request(1, 2).await;
+
+        // Assert
+        let error = result.expect_err("should be an error");
+        assert_eq!(
+            error.operation,
+            GazelleOperation::ApiResponse(ApiResponseKind::RequestAlreadyFilled)
+        );
+    }
+
+    #[tokio::test]
+    async fn mock_get_user_returns_configured_value() {
+        // Arrange
+        let expected = User::mock();
+        let mock = MockGazelleClient::new().with_get_user(Ok(expected.clone()));
+
+        // Act
+        let result = mock.get_user(1).await;
+
+        // Assert
+        assert!(result.is_ok());
+        let user = result.expect("should be ok");
+        assert_eq!(user.username, expected.username);
+    }
+
+    #[tokio::test]
+    async fn mock_get_index_returns_configured_value() {
+        // Arrange
+        let expected = IndexResponse::mock();
+        let mock = MockGazelleClient::new().with_get_index(Ok(expected.clone()));
+
+        // Act
+        let result = mock.get_index().await;
+
+        // Assert
+        let response = result.expect("should be ok");
+        assert_eq!(response.id, expected.id);
+        assert_eq!(response.username, expected.username);
+    }
+
+    #[tokio::test]
+    async fn mock_get_request_returns_configured_value() {
+        // Arrange
+        let expected = RequestDetail::mock();
+        let mock = MockGazelleClient::new().with_get_request(Ok(expected.clone()));
+
+        // Act
+        let result = mock.get_request(expected.request_id).await;
+
+        // Assert
+        let response = result.expect("should be ok");
+        assert_eq!(response.request_id, expected.request_id);
+        assert_eq!(response.format_list, expected.format_list);
+    }
+
+    #[tokio::test]
+    async fn mock_download_torrent_returns_bytes() {
+        // Arrange
+        let expected_bytes = vec![0xd8, 0x3a, 0x00]; // Some bytes
+        let mock = MockGazelleClient::new().with_download_torrent(Ok(expected_bytes.clone()));
+
+        // Act
+        let result = mock.download_torrent(123).await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(result.expect("should be ok"), expected_bytes);
+    }
+
+    #[tokio::test]
+    async fn mock_works_as_trait_object() {
+        // Arrange - Create mock as trait object for dependency injection
+        let response = TorrentResponse::mock();
+        let mock = MockGazelleClient::new().with_get_torrent(Ok(response));
+
+        // Use as dyn GazelleClientTrait for dependency injection pattern
+        let client: Arc<Mutex<dyn GazelleClientTrait>> = Arc::new(Mutex::new(mock));
+
+        // Act - Use the trait object
+        let result = client.lock().await.get_torrent(123).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn mock_can_be_called_multiple_times() {
+        // Arrange
+        let expected = TorrentResponse::mock();
+        let mock = MockGazelleClient::new().with_get_torrent(Ok(expected.clone()));
+
+        // Act - Call multiple times
+        let result1 = mock.get_torrent(123).await;
+        let result2 = mock.get_torrent(456).await;
+        let result3 = mock.get_torrent(789).await;
+
+        // Assert - All calls return the same configured value
+        assert!(result1.is_ok());
+        assert!(result2.is_ok());
+        assert!(result3.is_ok());
+        assert_eq!(
+            result1.expect("should be ok").torrent.id,
+            expected.torrent.id
+        );
+        assert_eq!(
+            result2.expect("should be ok").torrent.id,
+            expected.torrent.id
+        );
+        assert_eq!(
+            result3.expect("should be ok").torrent.id,
+            expected.torrent.id
+        );
+    }
+
+    #[tokio::test]
+    async fn mock_default_has_all_ok_responses() {
+        // Arrange
+        let mock = MockGazelleClient::default();
+
+        // Act & Assert - All methods return Ok
+        assert!(mock.get_torrent(1).await.is_ok());
+        assert!(mock.get_torrent_group(1).await.is_ok());
+        assert!(mock.get_artist(ArtistLookup::Id(1)).await.is_ok());
+        assert!(
+            mock.search_requests(&RequestSearch::default())
+                .await
+                .is_ok()
+        );
+        assert!(mock.get_request(1).await.is_ok());
+        assert!(mock.fill_request(1, 1).await.is_ok());
+        assert!(mock.get_user(1).await.is_ok());
+        assert!(
+            mock.get_user_torrents(1, UserTorrentKind::Seeding, 10, 0)
+                .await
+                .is_ok()
+        );
+        assert!(mock.get_index().await.is_ok());
+        assert!(mock.download_torrent(1).await.is_ok());
+        assert!(
+            mock.download_torrent_to_path(1, Path::new("1.torrent"))
+                .await
+                .is_ok()
+        );
+        assert!(
+            mock.upload_torrent(UploadForm {
+                path: PathBuf::new(),
+                category_id: Category::Music,
+                remaster_year: 2020,
+                remaster_title: String::new(),
+                remaster_record_label: String::new(),
+                remaster_catalogue_number: String::new(),
+                format: Format::FLAC,
+                bitrate: Quality::Lossless,
+                media: Media::CD,
+                release_desc: String::new(),
+                group_id: 1,
+            })
+            .await
+            .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn mock_get_torrent_by_id() {
+        // Arrange
+        let first = TorrentResponse::mock();
+        let mut second = TorrentResponse::mock();
+        second.torrent.id = 2;
+        let mock = MockGazelleClient::new()
+            .with_get_torrent(Err(GazelleError::not_found("not found".to_owned(), 404)))
+            .on_get_torrent(MockRule::matching(1).returns(Ok(first.clone())))
+            .on_get_torrent(MockRule::matching(2).returns(Ok(second)));
+
+        // Act
+        let result1 = mock.get_torrent(1).await;
+        let result2 = mock.get_torrent(2).await;
+        let result3 = mock.get_torrent(3).await;
+
+        // Assert
+        assert_eq!(result1.expect("should be ok").torrent.id, first.torrent.id);
+        assert_eq!(result2.expect("should be ok").torrent.id, 2);
+        assert!(result3.expect_err("should be an error").is_missing());
+    }
+
+    #[tokio::test]
+    async fn mock_browse_by_request() {
+        // Arrange
+        let request = BrowseRequest {
+            page: Some(2),
+            ..BrowseRequest::default()
+        };
+        let mock = MockGazelleClient::new()
+            .with_browse(Ok(BrowseResponse::mock()))
+            .on_browse(
+                MockRule::matching(request.clone())
+                    .returns(Err(GazelleError::bad_request("bad page".to_owned(), 400))),
+            );
+
+        // Act
+        let matched = mock.browse(&request).await;
+        let other = mock.browse(&BrowseRequest::default()).await;
+
+        // Assert
+        assert!(matched.is_err());
+        assert!(other.is_ok());
+    }
+
+    #[tokio::test]
+    async fn mock_get_torrent_by_hash_predicate() {
+        // Arrange
+        let mock = MockGazelleClient::new().on_get_torrent_by_hash(
+            MockRule::when(|hash: &String| hash.eq_ignore_ascii_case("abc123"))
+                .returns(Ok(TorrentResponse::mock())),
+        );
+
+        // Act
+        let result = mock.get_torrent_by_hash("ABC123").await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn mock_get_torrent_sequence() {
+        // Arrange
+        let mock = MockGazelleClient::new().on_get_torrent(
+            MockRule::any()
+                .returns(Err(GazelleError::too_many_requests(
+                    "Rate limit exceeded".to_owned(),
+                    429,
+                )))
+                .returns(Ok(TorrentResponse::mock())),
+        );
+
+        // Act
+        let result1 = mock.get_torrent(1).await;
+        let result2 = mock.get_torrent(1).await;
+        let result3 = mock.get_torrent(1).await;
+
+        // Assert
+        assert_eq!(
+            result1.expect_err("should be an error").operation,
+            GazelleOperation::ApiResponse(ApiResponseKind::TooManyRequests)
+        );
+        assert!(result2.is_ok());
+        assert!(result3.is_ok());
+    }
+
+    #[tokio::test]
+    async fn mock_sequence_repeats_last_result() {
+        // Arrange
+        let mut second = TorrentResponse::mock();
+        second.torrent.id = 2;
+        let mock = MockGazelleClient::new().on_get_torrent(
+            MockRule::any()
+                .returns(Ok(TorrentResponse::mock()))
+                .returns(Ok(second)),
+        );
+
+        // Act
+        let mut ids = Vec::new();
+        for _ in 0..4 {
+            let response = mock.get_torrent(1).await.expect("should be ok");
+            ids.push(response.torrent.id);
+        }
+
+        // Assert
+        let first = TorrentResponse::mock().torrent.id;
+        assert_eq!(ids, vec![first, 2, 2, 2]);
+    }
+
+    #[tokio::test]
+    async fn mock_first_matching_rule_wins() {
+        // Arrange
+        let mut second = TorrentResponse::mock();
+        second.torrent.id = 2;
+        let mut fallback = TorrentResponse::mock();
+        fallback.torrent.id = 3;
+        let mock = MockGazelleClient::new()
+            .with_get_torrent(Ok(fallback))
+            .on_get_torrent(
+                MockRule::when(|id: &u32| *id < 10).returns(Ok(TorrentResponse::mock())),
+            )
+            .on_get_torrent(MockRule::matching(2).returns(Ok(second)));
+
+        // Act
+        let matched_both = mock.get_torrent(2).await.expect("should be ok");
+        let matched_none = mock.get_torrent(20).await.expect("should be ok");
+
+        // Assert
+        assert_eq!(matched_both.torrent.id, TorrentResponse::mock().torrent.id);
+        assert_eq!(matched_none.torrent.id, 3);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MockGazelleClient: no get_torrent result for the arguments")]
+    async fn mock_without_result_panics() {
+        // Arrange
+        let mock = MockGazelleClient::new()
+            .on_get_torrent(MockRule::matching(1).returns(Ok(TorrentResponse::mock())));
+
+        // Act
+        let _ = mock.get_torrent(2).await;
+    }
+
+    #[tokio::test]
+    async fn mock_records_calls() {
+        // Arrange
+        let mock = MockGazelleClient::default();
+        let clone = mock.clone();
+
+        // Act
+        let _ = mock.get_torrent(123).await;
+        let _ = clone.fill_request(1, 2).await;
+        let _ = mock.get_index().await;
+
+        // Assert
+        assert_eq!(
+            mock.calls(),
+            vec![
+                MockCall::GetTorrent(123),
+                MockCall::FillRequest(1, 2),
+                MockCall::GetIndex,
+            ]
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use crate::prelude::*;
+use std::sync::{Arc, Mutex};
+
+/// Configured results of one [`MockGazelleClient`] method.
+#[derive(Clone, Debug)]
+pub(crate) struct MockMethod<A, T> {
+    /// Message of the panic if nothing is configured for the arguments
+    unset: &'static str,
+    /// Rules checked in the order they were added
+    rules: Arc<Mutex<Vec<MockRule<A, T>>>>,
+    /// Result if no rule matches
+    returns: Option<Result<T, GazelleError>>,
+}
+
+impl<A, T: Clone> MockMethod<A, T> {
+    pub(crate) fn new(unset: &'static str) -> Self {
+        Self {
+            unset,
+            rules: Arc::new(Mutex::new(Vec::new())),
+            returns: None,
+        }
+    }
+
+    pub(crate) fn with_returns(mut self, result: Result<T, GazelleError>) -> Self {
+        self.returns = Some(result);
+        self
+    }
+
+    pub(crate) fn with_rule(self, rule: MockRule<A, T>) -> Self {
+        self.rules
+            .lock()
+            .expect("mock rules lock should not be poisoned")
+            .push(rule);
+        self
+    }
+
+    /// Result of the first matching rule, or the configured result.
+    ///
+    /// # Panics
+    /// - If nothing is configured for the arguments
+    pub(crate) fn call(&self, args: &A) -> Result<T, GazelleError> {
+        let mut rules = self
+            .rules
+            .lock()
+            .expect("mock rules lock should not be poisoned");
+        rules
+            .iter_mut()
+            .find_map(|rule| rule.next(args))
+            .or_else(|| self.returns.clone())
+            .expect(self.unset)
+    }
+}
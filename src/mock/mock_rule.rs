@@ -0,0 +1,114 @@
+use crate::prelude::*;
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::sync::Arc;
+
+type Matcher<A> = Arc<dyn Fn(&A) -> bool + Send + Sync>;
+
+/// Results a [`MockGazelleClient`] method returns when its arguments match.
+///
+/// - Results are returned in order, then the last result is repeated
+/// - A rule without results never matches
+///
+/// Example: `MockRule::matching(123).returns(Err(rate_limited)).returns(Ok(response))`
+/// fails the first call for torrent 123 then succeeds.
+pub struct MockRule<A, T> {
+    matcher: Matcher<A>,
+    results: VecDeque<Result<T, GazelleError>>,
+}
+
+impl<A: 'static, T> MockRule<A, T> {
+    /// Create a rule matching any arguments
+    #[must_use]
+    pub fn any() -> Self {
+        Self::when(|_| true)
+    }
+
+    /// Create a rule matching arguments equal to `args`
+    #[must_use]
+    pub fn matching(args: A) -> Self
+    where
+        A: PartialEq + Send + Sync,
+    {
+        Self::when(move |actual| *actual == args)
+    }
+
+    /// Create a rule matching arguments that satisfy `predicate`
+    #[must_use]
+    pub fn when(predicate: impl Fn(&A) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            matcher: Arc::new(predicate),
+            results: VecDeque::new(),
+        }
+    }
+
+    /// Add a result to the end of the sequence
+    #[must_use]
+    pub fn returns(mut self, result: Result<T, GazelleError>) -> Self {
+        self.results.push_back(result);
+        self
+    }
+}
+
+impl<A, T> MockRule<A, T> {
+    /// Next result if the arguments match
+    pub(crate) fn next(&mut self, args: &A) -> Option<Result<T, GazelleError>>
+    where
+        T: Clone,
+    {
+        if !(self.matcher)(args) {
+            return None;
+        }
+        if self.results.len() > 1 {
+            self.results.pop_front()
+        } else {
+            self.results.front().cloned()
+        }
+    }
+}
+
+impl<A, T> Debug for MockRule<A, T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        formatter
+            .debug_struct("MockRule")
+            .field("results", &self.results.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_repeats_last_result() {
+        // Arrange
+        let mut rule = MockRule::<u32, u32>::any().returns(Ok(1)).returns(Ok(2));
+
+        // Act
+        let results: Vec<_> = (0..3).filter_map(|id| rule.next(&id)?.ok()).collect();
+
+        // Assert
+        assert_eq!(results, vec![1, 2, 2]);
+    }
+
+    #[test]
+    fn next_skips_other_arguments() {
+        // Arrange
+        let mut rule = MockRule::<String, u32>::matching("abc".to_owned()).returns(Ok(1));
+
+        // Act
+        let other = rule.next(&"def".to_owned());
+        let matched = rule.next(&"abc".to_owned());
+
+        // Assert
+        assert!(other.is_none());
+        assert_eq!(matched.and_then(Result::ok), Some(1));
+    }
+
+    #[test]
+    fn next_without_results() {
+        let mut rule = MockRule::<u32, u32>::any();
+        assert!(rule.next(&1).is_none());
+    }
+}
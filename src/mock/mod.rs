@@ -0,0 +1,9 @@
+pub use mock_call::*;
+pub use mock_client::*;
+pub(crate) use mock_method::*;
+pub use mock_rule::*;
+
+mod mock_call;
+mod mock_client;
+mod mock_method;
+mod mock_rule;
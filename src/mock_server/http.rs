@@ -0,0 +1,96 @@
+use crate::prelude::*;
+use std::borrow::Cow;
+use std::fmt::Write as FmtWrite;
+use std::io::ErrorKind;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use urlencoding::decode;
+
+/// Read a HTTP/1.1 request.
+///
+/// - The body is read according to `Content-Length`, chunked bodies are not supported
+pub(crate) async fn read_request<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+) -> Result<MockRequest, IoError> {
+    let line = read_line(reader).await?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(IoError::new(
+            ErrorKind::InvalidData,
+            format!("invalid request line: {line}"),
+        ));
+    };
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let mut request = MockRequest {
+        method: method.to_owned(),
+        path: path.to_owned(),
+        query: parse_query(query),
+        headers: Vec::new(),
+        body: Vec::new(),
+    };
+    loop {
+        let line = read_line(reader).await?;
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            request
+                .headers
+                .push((name.trim().to_lowercase(), value.trim().to_owned()));
+        }
+    }
+    let length = request
+        .get_header("content-length")
+        .and_then(|length| length.parse::<usize>().ok())
+        .unwrap_or_default();
+    request.body = vec![0; length];
+    reader.read_exact(&mut request.body).await?;
+    Ok(request)
+}
+
+/// Write a response and close the connection.
+pub(crate) async fn write_response<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    response: &MockResponse,
+) -> Result<(), IoError> {
+    let reason = StatusCode::from_u16(response.status)
+        .ok()
+        .and_then(|status| status.canonical_reason())
+        .unwrap_or_default();
+    let mut head = format!(
+        "HTTP/1.1 {} {reason}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        response.content_type,
+        response.body.len()
+    );
+    for (name, value) in &response.headers {
+        let _ = write!(head, "{name}: {value}\r\n");
+    }
+    head.push_str("\r\n");
+    writer.write_all(head.as_bytes()).await?;
+    writer.write_all(&response.body).await?;
+    writer.flush().await
+}
+
+/// Read a line without the trailing `\r\n`.
+async fn read_line<R: AsyncBufRead + Unpin>(reader: &mut R) -> Result<String, IoError> {
+    let mut line = String::new();
+    if reader.read_line(&mut line).await? == 0 {
+        return Err(IoError::from(ErrorKind::UnexpectedEof));
+    }
+    Ok(line.trim_end().to_owned())
+}
+
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode_component(name), decode_component(value))
+        })
+        .collect()
+}
+
+fn decode_component(value: &str) -> String {
+    decode(value).map_or_else(|_| value.to_owned(), Cow::into_owned)
+}
@@ -0,0 +1,220 @@
+use crate::prelude::*;
+use serde_json::from_value as json_from_value;
+use std::collections::{BTreeMap, HashMap};
+
+/// Size of each piece in a generated `.torrent` file.
+const PIECE_LENGTH: i64 = 256 * 1024;
+
+/// Values of the fixtures of a [`MockIndexer`] that the server looks requests up by.
+///
+/// The fixtures are sanitized independently so their ids do not refer to each other.
+/// The torrent, group, artist and browse fixtures are linked by the ids of the group
+/// fixture and its first artist before they are served:
+///
+/// - The group of the torrent fixture is the group fixture
+/// - The group fixture contains the torrent fixture
+/// - The artist fixture is the first artist of the group fixture and its first group
+///   is the group fixture
+/// - The first result of the browse fixture is the torrent fixture in the group fixture
+pub(crate) struct MockFixtures {
+    pub torrent_id: u32,
+    pub group_id: u32,
+    pub artist_id: u32,
+    pub artist_name: String,
+    pub user_id: u32,
+    pub request_id: u32,
+    /// `.torrent` file generated from the torrent fixture
+    pub torrent_file: Vec<u8>,
+    /// Info hash of `torrent_file` as uppercase hex
+    pub info_hash: String,
+    /// Linked fixtures replacing those of the indexer, by action
+    linked: HashMap<&'static str, String>,
+}
+
+impl MockFixtures {
+    pub(crate) fn new(indexer: MockIndexer) -> Self {
+        let mut torrent = parse_fixture(indexer, "torrent");
+        let mut group = parse_fixture(indexer, "torrentgroup");
+        let mut artist = parse_fixture(indexer, "artist");
+        let mut browse = parse_fixture(indexer, "browse");
+        let index: IndexResponse = deserialize(parse_fixture(indexer, "index"));
+        let request: RequestDetail = deserialize(parse_fixture(indexer, "request"));
+        let response: TorrentResponse = deserialize(torrent.clone());
+        let torrent_id = response.torrent.id;
+        let group_response: GroupResponse = deserialize(group.clone());
+        let group_id = group_response.group.id;
+        let credit = group_response
+            .group
+            .music_info
+            .and_then(|info| info.artists.into_iter().next())
+            .expect("group fixture should have an artist");
+        link_group(&mut group, &torrent, torrent_id);
+        link_torrent(&mut torrent, &group);
+        link_artist(&mut artist, &credit, group_id);
+        link_browse(&mut browse, group_id, torrent_id);
+        let torrent_file = create_torrent_file(&response.torrent, indexer.source());
+        let info_hash = TorrentFile::from_bytes(&torrent_file)
+            .expect("generated torrent file should parse")
+            .info_hash_hex()
+            .to_uppercase();
+        let linked = HashMap::from([
+            ("torrent", torrent.to_string()),
+            ("torrentgroup", group.to_string()),
+            ("artist", artist.to_string()),
+            ("browse", browse.to_string()),
+        ]);
+        Self {
+            torrent_id,
+            group_id,
+            artist_id: credit.id,
+            artist_name: credit.name,
+            user_id: index.id,
+            request_id: request.request_id,
+            torrent_file,
+            info_hash,
+            linked,
+        }
+    }
+
+    /// JSON fixture served for an action.
+    pub(crate) fn get_fixture(&self, indexer: MockIndexer, action: &str) -> Option<String> {
+        match self.linked.get(action) {
+            Some(json) => Some(json.clone()),
+            None => indexer.get_fixture(action).map(str::to_owned),
+        }
+    }
+}
+
+fn parse_fixture(indexer: MockIndexer, action: &str) -> JsonValue {
+    let json = indexer
+        .get_fixture(action)
+        .expect("fixture should exist for action");
+    json_from_str(json).expect("fixture should deserialize")
+}
+
+fn deserialize<T: DeserializeOwned>(value: JsonValue) -> T {
+    json_from_value(value).expect("fixture should deserialize")
+}
+
+/// Replace or add the torrent fixture in the torrents of the group fixture.
+fn link_group(group: &mut JsonValue, torrent: &JsonValue, torrent_id: u32) {
+    let Some(torrents) = group.get_mut("torrents").and_then(JsonValue::as_array_mut) else {
+        return;
+    };
+    let torrent = torrent.get("torrent").cloned().unwrap_or_default();
+    let id = JsonValue::from(torrent_id);
+    match torrents
+        .iter_mut()
+        .find(|entry| entry.get("id") == Some(&id))
+    {
+        Some(entry) => *entry = torrent,
+        None => torrents.push(torrent),
+    }
+}
+
+/// Replace the group of the torrent fixture with the group fixture.
+fn link_torrent(torrent: &mut JsonValue, group: &JsonValue) {
+    if let (Some(entry), Some(group)) = (torrent.get_mut("group"), group.get("group")) {
+        *entry = group.clone();
+    }
+}
+
+/// Make the artist fixture the credited artist with the group fixture as its first group.
+fn link_artist(artist: &mut JsonValue, credit: &Credit, group_id: u32) {
+    set_id(artist, "id", credit.id);
+    if let Some(name) = artist.get_mut("name") {
+        *name = JsonValue::from(credit.name.as_str());
+    }
+    let Some(first) = artist
+        .get_mut("torrentgroup")
+        .and_then(JsonValue::as_array_mut)
+        .and_then(|groups| groups.first_mut())
+    else {
+        return;
+    };
+    set_id(first, "groupId", group_id);
+    for torrent in first
+        .get_mut("torrent")
+        .and_then(JsonValue::as_array_mut)
+        .into_iter()
+        .flatten()
+    {
+        set_id(torrent, "groupId", group_id);
+    }
+}
+
+/// Make the first result of the browse fixture the torrent fixture in the group fixture.
+fn link_browse(browse: &mut JsonValue, group_id: u32, torrent_id: u32) {
+    let Some(first) = browse
+        .get_mut("results")
+        .and_then(JsonValue::as_array_mut)
+        .and_then(|results| results.first_mut())
+    else {
+        return;
+    };
+    set_id(first, "groupId", group_id);
+    if let Some(torrent) = first
+        .get_mut("torrents")
+        .and_then(JsonValue::as_array_mut)
+        .and_then(|torrents| torrents.first_mut())
+    {
+        set_id(torrent, "torrentId", torrent_id);
+    }
+}
+
+/// Replace the id under `key`, keeping it a string if the fixture quotes it.
+fn set_id(value: &mut JsonValue, key: &str, id: u32) {
+    if let Some(entry) = value.get_mut(key) {
+        *entry = if entry.is_string() {
+            JsonValue::from(id.to_string())
+        } else {
+            JsonValue::from(id)
+        };
+    }
+}
+
+/// Create a `.torrent` file with the name and files of a [`Torrent`].
+///
+/// - Pieces are empty as there is no content to hash
+fn create_torrent_file(torrent: &Torrent, source: &str) -> Vec<u8> {
+    let files = torrent.get_files();
+    let file_path = torrent.file_path.as_deref().unwrap_or_default();
+    let mut info = BTreeMap::new();
+    if let (true, [file]) = (file_path.is_empty(), files.as_slice()) {
+        info.insert(b"name".to_vec(), bytes(&file.name));
+        info.insert(b"length".to_vec(), integer(file.size));
+    } else {
+        let files = files
+            .iter()
+            .map(|file| {
+                let path = file.name.split('/').map(bytes).collect();
+                BencodeValue::Dictionary(BTreeMap::from([
+                    (b"length".to_vec(), integer(file.size)),
+                    (b"path".to_vec(), BencodeValue::List(path)),
+                ]))
+            })
+            .collect();
+        info.insert(b"name".to_vec(), bytes(file_path));
+        info.insert(b"files".to_vec(), BencodeValue::List(files));
+    }
+    info.insert(
+        b"piece length".to_vec(),
+        BencodeValue::Integer(PIECE_LENGTH),
+    );
+    info.insert(b"pieces".to_vec(), BencodeValue::Bytes(Vec::new()));
+    info.insert(b"private".to_vec(), BencodeValue::Integer(1));
+    info.insert(b"source".to_vec(), bytes(source));
+    BencodeValue::Dictionary(BTreeMap::from([
+        (b"announce".to_vec(), bytes("http://127.0.0.1/announce")),
+        (b"info".to_vec(), BencodeValue::Dictionary(info)),
+    ]))
+    .encode()
+}
+
+fn bytes(value: &str) -> BencodeValue {
+    BencodeValue::Bytes(value.as_bytes().to_vec())
+}
+
+fn integer(value: u64) -> BencodeValue {
+    BencodeValue::Integer(i64::try_from(value).unwrap_or(i64::MAX))
+}
@@ -0,0 +1,107 @@
+use crate::prelude::*;
+
+/// Indexer whose responses a [`MockGazelleServer`] imitates.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MockIndexer {
+    /// Orpheus
+    ///
+    /// - Successful responses include an `info` object
+    /// - Errors are `200 OK` with a malformed `"response":[]`
+    Ops,
+    /// Redacted
+    ///
+    /// - Errors use the `4xx` status code
+    Red,
+}
+
+impl MockIndexer {
+    /// Source flag of the indexer, as set in its `.torrent` files.
+    #[must_use]
+    pub fn source(self) -> &'static str {
+        match self {
+            Self::Ops => "OPS",
+            Self::Red => "RED",
+        }
+    }
+
+    /// Wrap the JSON of a response in a successful envelope.
+    #[must_use]
+    pub fn success(self, response: &str) -> MockResponse {
+        let body = match self {
+            Self::Ops => format!(
+                r#"{{"status":"success","response":{response},"info":{{"source":"Orpheus","version":1}}}}"#
+            ),
+            Self::Red => format!(r#"{{"status":"success","response":{response}}}"#),
+        };
+        MockResponse::json(200, body)
+    }
+
+    /// Create an error response.
+    ///
+    /// - OPS ignores `status` and always responds `200 OK`
+    #[must_use]
+    pub fn failure(self, status: u16, error: &str) -> MockResponse {
+        let error = JsonValue::from(error);
+        match self {
+            Self::Ops => MockResponse::json(
+                200,
+                format!(r#"{{"status":"failure","response":[],"error":{error}}}"#),
+            ),
+            Self::Red => {
+                MockResponse::json(status, format!(r#"{{"status":"failure","error":{error}}}"#))
+            }
+        }
+    }
+
+    /// Create a rate limit exceeded response.
+    #[must_use]
+    pub fn rate_limited(self) -> MockResponse {
+        self.failure(429, "Rate limit exceeded")
+    }
+
+    /// JSON fixture served for an action.
+    pub(crate) fn get_fixture(self, action: &str) -> Option<&'static str> {
+        let fixture = match (self, action) {
+            (Self::Ops, "artist") => include_str!("../tests/fixtures/artist_response_ops.json"),
+            (Self::Red, "artist") => include_str!("../tests/fixtures/artist_response_red.json"),
+            (Self::Ops, "browse") => include_str!("../tests/fixtures/browse_response_ops.json"),
+            (Self::Red, "browse") => include_str!("../tests/fixtures/browse_response_red.json"),
+            (Self::Ops, "index") => include_str!("../tests/fixtures/index_response_ops.json"),
+            (Self::Red, "index") => include_str!("../tests/fixtures/index_response_red.json"),
+            (Self::Ops, "request") => include_str!("../tests/fixtures/request_detail_ops.json"),
+            (Self::Red, "request") => include_str!("../tests/fixtures/request_detail_red.json"),
+            (Self::Ops, "requestfill") => {
+                include_str!("../tests/fixtures/fill_request_response_ops.json")
+            }
+            (Self::Red, "requestfill") => {
+                include_str!("../tests/fixtures/fill_request_response_red.json")
+            }
+            (Self::Ops, "requests") => {
+                include_str!("../tests/fixtures/request_search_response_ops.json")
+            }
+            (Self::Red, "requests") => {
+                include_str!("../tests/fixtures/request_search_response_red.json")
+            }
+            (Self::Ops, "torrent") => include_str!("../tests/fixtures/torrent_response_ops.json"),
+            (Self::Red, "torrent") => include_str!("../tests/fixtures/torrent_response_red.json"),
+            (Self::Ops, "torrentgroup") => {
+                include_str!("../tests/fixtures/group_response_ops.json")
+            }
+            (Self::Red, "torrentgroup") => {
+                include_str!("../tests/fixtures/group_response_red.json")
+            }
+            (Self::Ops, "upload") => include_str!("../tests/fixtures/upload_response_ops.json"),
+            (Self::Red, "upload") => include_str!("../tests/fixtures/upload_response_red.json"),
+            (Self::Ops, "user") => include_str!("../tests/fixtures/user_response_ops.json"),
+            (Self::Red, "user") => include_str!("../tests/fixtures/user_response_red.json"),
+            (Self::Ops, "user_torrents") => {
+                include_str!("../tests/fixtures/user_torrents_response_ops.json")
+            }
+            (Self::Red, "user_torrents") => {
+                include_str!("../tests/fixtures/user_torrents_response_red.json")
+            }
+            _ => return None,
+        };
+        Some(fixture)
+    }
+}
@@ -0,0 +1,58 @@
+/// A request received by a [`MockGazelleServer`](crate::MockGazelleServer).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MockRequest {
+    /// HTTP method
+    ///
+    /// Example: `GET`
+    pub method: String,
+    /// Path without the query string
+    ///
+    /// Example: `/ajax.php`
+    pub path: String,
+    /// Decoded query parameters in the order they were sent
+    pub query: Vec<(String, String)>,
+    /// Headers with lowercase names
+    pub headers: Vec<(String, String)>,
+    /// Request body
+    pub body: Vec<u8>,
+}
+
+impl MockRequest {
+    /// Get the first query parameter with the name.
+    #[must_use]
+    pub fn get_param(&self, name: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Get the first header with the name.
+    ///
+    /// - `name` is case-insensitive
+    #[must_use]
+    pub fn get_header(&self, name: &str) -> Option<&str> {
+        let name = name.to_lowercase();
+        self.headers
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Get the `action` query parameter.
+    #[must_use]
+    pub fn action(&self) -> Option<&str> {
+        self.get_param("action")
+    }
+
+    /// Get a text field of a `multipart/form-data` body.
+    #[must_use]
+    pub fn get_form_field(&self, name: &str) -> Option<String> {
+        let body = String::from_utf8_lossy(&self.body);
+        let marker = format!("name=\"{name}\"");
+        let (_, after) = body.split_once(&marker)?;
+        let (_, value) = after.split_once("\r\n\r\n")?;
+        let (value, _) = value.split_once("\r\n")?;
+        Some(value.to_owned())
+    }
+}
@@ -0,0 +1,59 @@
+use crate::prelude::*;
+
+/// A response served by a [`MockGazelleServer`].
+///
+/// Build with [`MockIndexer::success`] or [`MockIndexer::failure`] to match the
+/// format of an indexer, or with [`MockResponse::json`] for anything else.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MockResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// `Content-Type` header value
+    pub content_type: String,
+    /// Additional headers
+    pub headers: Vec<(String, String)>,
+    /// Response body
+    pub body: Vec<u8>,
+    /// Time to wait before sending the response
+    pub delay: Option<Duration>,
+}
+
+impl MockResponse {
+    /// Create a [`MockResponse`] with a JSON body
+    #[must_use]
+    pub fn json(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            content_type: "application/json; charset=utf-8".to_owned(),
+            headers: Vec::new(),
+            body: body.into().into_bytes(),
+            delay: None,
+        }
+    }
+
+    /// Create a [`MockResponse`] with a `.torrent` file body
+    #[must_use]
+    pub fn torrent(body: Vec<u8>) -> Self {
+        Self {
+            status: 200,
+            content_type: "application/x-bittorrent; charset=utf-8".to_owned(),
+            headers: Vec::new(),
+            body,
+            delay: None,
+        }
+    }
+
+    /// Add a header.
+    #[must_use]
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Wait before sending the response.
+    #[must_use]
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+}
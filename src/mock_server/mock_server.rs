@@ -0,0 +1,730 @@
+use crate::prelude::*;
+use serde_json::Map as JsonMap;
+use std::collections::HashMap;
+use std::net::{SocketAddr, TcpListener as StdTcpListener};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::Builder as ThreadBuilder;
+use tokio::io::BufReader;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::runtime::Builder as RuntimeBuilder;
+use tokio::sync::oneshot::{Receiver, Sender, channel};
+use tokio::{select, spawn};
+
+/// A fake Gazelle indexer serving the JSON fixtures over HTTP on `127.0.0.1`.
+///
+/// Exercises the request, response and error handling of a real [`GazelleClient`]
+/// without API credentials.
+///
+/// - Responses use the format of the [`MockIndexer`], including its quirks
+/// - Lookups by the id in a fixture succeed, any other id is an error
+/// - `download` serves a `.torrent` file generated from the torrent fixture, and
+///   `torrent` lookups by its info hash succeed
+/// - `browse` and `requests` serve the same results for every page
+/// - `upload` rejects a form without a remaster year
+/// - Runs on its own thread so it can outlive the runtime of a single test
+/// - Stops when dropped
+pub struct MockGazelleServer {
+    address: SocketAddr,
+    context: Arc<MockContext>,
+    shutdown: Option<Sender<()>>,
+}
+
+struct MockContext {
+    indexer: MockIndexer,
+    fixtures: MockFixtures,
+    state: Mutex<MockState>,
+}
+
+#[derive(Default)]
+struct MockState {
+    /// Responses replacing the fixtures, by action
+    responses: HashMap<String, MockResponse>,
+    /// Number of upcoming requests to reject as rate limited
+    rate_limited: usize,
+    /// Delay before every response without its own delay
+    delay: Option<Duration>,
+    requests: Vec<MockRequest>,
+}
+
+impl MockGazelleServer {
+    /// Start a [`MockGazelleServer`] on a free port.
+    pub fn start(indexer: MockIndexer) -> Result<Self, IoError> {
+        let listener = StdTcpListener::bind("127.0.0.1:0")?;
+        listener.set_nonblocking(true)?;
+        let address = listener.local_addr()?;
+        let context = Arc::new(MockContext {
+            indexer,
+            fixtures: MockFixtures::new(indexer),
+            state: Mutex::new(MockState::default()),
+        });
+        let runtime = RuntimeBuilder::new_current_thread().enable_all().build()?;
+        let (shutdown, receiver) = channel();
+        let server_context = context.clone();
+        ThreadBuilder::new()
+            .name(format!("mock-gazelle-{}", indexer.source()))
+            .spawn(move || runtime.block_on(serve(listener, server_context, receiver)))?;
+        trace!("Started mock {} server on {address}", indexer.source());
+        Ok(Self {
+            address,
+            context,
+            shutdown: Some(shutdown),
+        })
+    }
+
+    /// Indexer the responses imitate.
+    #[must_use]
+    pub fn indexer(&self) -> MockIndexer {
+        self.context.indexer
+    }
+
+    /// Base URL of the server.
+    ///
+    /// Example: `http://127.0.0.1:41234`
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Options for a client of this server.
+    #[must_use]
+    pub fn options(&self) -> GazelleClientOptions {
+        GazelleClientOptions {
+            user_agent: "gazelle_api mock".to_owned(),
+            key: "mock".to_owned(),
+            url: self.url(),
+            requests_allowed_per_duration: None,
+            request_limit_duration: None,
+            rate_limit_path: None,
+            adaptive_rate: None,
+            retry_delays: Vec::new(),
+            retry_policy: None,
+            retry_uploads: false,
+            priority: RequestPriority::default(),
+            timeout: None,
+            connect_timeout: None,
+            read_timeout: None,
+            proxy: None,
+            root_certificates: Vec::new(),
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            cassette: None,
+        }
+    }
+
+    /// Create a [`GazelleClient`] for this server with [`options`](Self::options).
+    #[must_use]
+    pub fn client(&self) -> GazelleClient {
+        GazelleClient::from(self.options())
+    }
+
+    /// ID of the torrent in the torrent fixture.
+    #[must_use]
+    pub fn torrent_id(&self) -> u32 {
+        self.context.fixtures.torrent_id
+    }
+
+    /// ID of the group in the torrent group fixture.
+    #[must_use]
+    pub fn group_id(&self) -> u32 {
+        self.context.fixtures.group_id
+    }
+
+    /// ID of the user in the index fixture.
+    #[must_use]
+    pub fn user_id(&self) -> u32 {
+        self.context.fixtures.user_id
+    }
+
+    /// `.torrent` file served by `download`.
+    #[must_use]
+    pub fn torrent_file(&self) -> &[u8] {
+        &self.context.fixtures.torrent_file
+    }
+
+    /// Serve `response` for every request of `action` instead of the fixture.
+    pub fn set_response(&self, action: &str, response: MockResponse) {
+        self.context
+            .lock_state()
+            .responses
+            .insert(action.to_owned(), response);
+    }
+
+    /// Serve the fixtures again for every action.
+    pub fn clear_responses(&self) {
+        self.context.lock_state().responses.clear();
+    }
+
+    /// Reject the next `count` requests as rate limited.
+    pub fn rate_limit(&self, count: usize) {
+        self.context.lock_state().rate_limited = count;
+    }
+
+    /// Wait before sending every response.
+    ///
+    /// - A [`MockResponse`] with its own delay ignores this
+    pub fn set_delay(&self, delay: Option<Duration>) {
+        self.context.lock_state().delay = delay;
+    }
+
+    /// Requests received so far, oldest first.
+    #[must_use]
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.context.lock_state().requests.clone()
+    }
+}
+
+impl Drop for MockGazelleServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+impl MockContext {
+    fn lock_state(&self) -> MutexGuard<'_, MockState> {
+        self.state
+            .lock()
+            .expect("mock server state lock should not be poisoned")
+    }
+
+    /// Record the request and decide the response.
+    fn respond(&self, request: MockRequest) -> MockResponse {
+        let mut state = self.lock_state();
+        let action = request.action().unwrap_or_default().to_owned();
+        let response = if state.rate_limited > 0 {
+            state.rate_limited -= 1;
+            self.indexer.rate_limited()
+        } else if let Some(response) = state.responses.get(&action) {
+            response.clone()
+        } else {
+            self.route(&request)
+        };
+        let delay = response.delay.or(state.delay);
+        state.requests.push(request);
+        MockResponse { delay, ..response }
+    }
+
+    /// Serve the fixture for the request.
+    fn route(&self, request: &MockRequest) -> MockResponse {
+        let indexer = self.indexer;
+        let fixtures = &self.fixtures;
+        if request.path != "/ajax.php" {
+            return indexer.failure(404, "endpoint not found");
+        }
+        let id = request
+            .get_param("id")
+            .and_then(|id| id.parse::<u32>().ok());
+        match request.action().unwrap_or_default() {
+            "index" => self.fixture("index"),
+            "torrent" => {
+                let found = match request.get_param("hash") {
+                    Some(hash) => hash.eq_ignore_ascii_case(&fixtures.info_hash),
+                    None => id == Some(fixtures.torrent_id),
+                };
+                self.fixture_if("torrent", found)
+            }
+            "torrentgroup" => self.fixture_if("torrentgroup", id == Some(fixtures.group_id)),
+            "artist" => {
+                let found = id == Some(fixtures.artist_id)
+                    || request.get_param("artistname") == Some(fixtures.artist_name.as_str());
+                self.fixture_if("artist", found)
+            }
+            "user" | "user_torrents" if id != Some(fixtures.user_id) => {
+                indexer.failure(400, "no such user")
+            }
+            "user" => self.fixture("user"),
+            "user_torrents" => self.get_user_torrents(request),
+            "request" => self.fixture_if("request", id == Some(fixtures.request_id)),
+            "browse" => self.get_page("browse", request),
+            "requests" => self.get_page("requests", request),
+            "download" if id == Some(fixtures.torrent_id) => {
+                MockResponse::torrent(fixtures.torrent_file.clone())
+            }
+            "download" => indexer.failure(404, "could not find torrent"),
+            "requestfill" => {
+                let request_id = request
+                    .get_form_field("requestid")
+                    .and_then(|id| id.parse::<u32>().ok());
+                self.fixture_if("requestfill", request_id == Some(fixtures.request_id))
+            }
+            "upload" if request.get_form_field("remaster_year").as_deref() == Some("0") => {
+                indexer.failure(400, "Invalid remaster year.")
+            }
+            "upload" => self.fixture("upload"),
+            _ => indexer.failure(404, "endpoint not found"),
+        }
+    }
+
+    fn fixture(&self, action: &str) -> MockResponse {
+        let json = self
+            .fixtures
+            .get_fixture(self.indexer, action)
+            .expect("fixture should exist for action");
+        self.indexer.success(&json)
+    }
+
+    /// The fixture if `found`, otherwise a `bad id parameter` error.
+    fn fixture_if(&self, action: &str, found: bool) -> MockResponse {
+        if found {
+            self.fixture(action)
+        } else {
+            self.indexer.failure(400, "bad id parameter")
+        }
+    }
+
+    /// The fixture with `currentPage` set to the requested page.
+    fn get_page(&self, action: &str, request: &MockRequest) -> MockResponse {
+        let page = request
+            .get_param("page")
+            .and_then(|page| page.parse::<u32>().ok())
+            .unwrap_or(1);
+        let json = self
+            .fixtures
+            .get_fixture(self.indexer, action)
+            .expect("fixture should exist for action");
+        let mut response: JsonValue = json_from_str(&json).expect("fixture should deserialize");
+        if let Some(response) = response.as_object_mut() {
+            response.insert("currentPage".to_owned(), JsonValue::from(page));
+        }
+        self.indexer.success(&response.to_string())
+    }
+
+    /// The entries of the fixture under the requested type, limited and offset.
+    fn get_user_torrents(&self, request: &MockRequest) -> MockResponse {
+        let kind = request.get_param("type").unwrap_or("seeding");
+        let limit = get_usize_param(request, "limit").unwrap_or(usize::MAX);
+        let offset = get_usize_param(request, "offset").unwrap_or_default();
+        let json = self
+            .indexer
+            .get_fixture("user_torrents")
+            .expect("fixture should exist for action");
+        let fixture: JsonValue = json_from_str(json).expect("fixture should deserialize");
+        let entries: Vec<JsonValue> = fixture
+            .as_object()
+            .and_then(|lists| lists.values().find_map(JsonValue::as_array))
+            .map(|entries| entries.iter().skip(offset).take(limit).cloned().collect())
+            .unwrap_or_default();
+        let mut response = JsonMap::new();
+        response.insert(kind.to_owned(), JsonValue::Array(entries));
+        self.indexer
+            .success(&JsonValue::Object(response).to_string())
+    }
+}
+
+fn get_usize_param(request: &MockRequest, name: &str) -> Option<usize> {
+    request
+        .get_param(name)
+        .and_then(|value| value.parse::<usize>().ok())
+}
+
+/// Accept connections until `shutdown` is sent or dropped.
+async fn serve(listener: StdTcpListener, context: Arc<MockContext>, mut shutdown: Receiver<()>) {
+    let listener = match TcpListener::from_std(listener) {
+        Ok(listener) => listener,
+        Err(error) => {
+            warn!("Failed to start mock server: {error}");
+            return;
+        }
+    };
+    loop {
+        select! {
+            _ = &mut shutdown => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    spawn(handle_connection(stream, context.clone()));
+                }
+                Err(error) => warn!("Mock server failed to accept connection: {error}"),
+            },
+        }
+    }
+}
+
+/// Serve a single request then close the connection.
+async fn handle_connection(stream: TcpStream, context: Arc<MockContext>) {
+    let mut reader = BufReader::new(stream);
+    let request = match read_request(&mut reader).await {
+        Ok(request) => request,
+        Err(error) => {
+            trace!("Mock server failed to read request: {error}");
+            return;
+        }
+    };
+    trace!("Mock server received {} {}", request.method, request.path);
+    let response = context.respond(request);
+    if let Some(delay) = response.delay {
+        tokio_sleep(delay).await;
+    }
+    if let Err(error) = write_response(reader.get_mut(), &response).await {
+        trace!("Mock server failed to write response: {error}");
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::indexing_slicing,
+    reason = "test assertions on known fixture data"
+)]
+mod tests {
+    use super::*;
+    use std::env::temp_dir;
+    use std::fs::remove_dir_all;
+
+    const INDEXERS: [MockIndexer; 2] = [MockIndexer::Ops, MockIndexer::Red];
+
+    fn start(indexer: MockIndexer) -> MockGazelleServer {
+        MockGazelleServer::start(indexer).expect("server should start")
+    }
+
+    fn get_status(error: GazelleError) -> u16 {
+        let ErrorSource::ApiResponse(error) = error.source else {
+            unreachable!()
+        };
+        error.status
+    }
+
+    #[tokio::test]
+    async fn get_torrent_serves_fixture() {
+        for indexer in INDEXERS {
+            // Arrange
+            let server = start(indexer);
+            let client = server.client();
+
+            // Act
+            let response = client
+                .get_torrent(server.torrent_id())
+                .await
+                .expect("should get torrent");
+
+            // Assert
+            assert_eq!(response.torrent.id, server.torrent_id());
+            let requests = server.requests();
+            assert_eq!(requests.len(), 1);
+            assert_eq!(requests[0].action(), Some("torrent"));
+            assert_eq!(requests[0].get_header("Authorization"), Some("mock"));
+        }
+    }
+
+    #[tokio::test]
+    async fn get_torrent_unknown_id() {
+        for (indexer, status) in [(MockIndexer::Ops, 200), (MockIndexer::Red, 400)] {
+            // Arrange
+            let server = start(indexer);
+
+            // Act
+            let error = server
+                .client()
+                .get_torrent(u32::MAX)
+                .await
+                .expect_err("should be an error");
+
+            // Assert
+            assert_eq!(
+                error.operation,
+                GazelleOperation::ApiResponse(ApiResponseKind::BadRequest)
+            );
+            assert_eq!(get_status(error), status, "{indexer:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn download_torrent_matches_fixture() {
+        for indexer in INDEXERS {
+            // Arrange
+            let server = start(indexer);
+            let client = server.client();
+            let source = client
+                .get_torrent(server.torrent_id())
+                .await
+                .expect("should get torrent");
+
+            // Act
+            let bytes = client
+                .download_torrent(server.torrent_id())
+                .await
+                .expect("should download");
+
+            // Assert
+            let mismatches = verify_metainfo(&source.torrent, &bytes).expect("should parse");
+            assert!(mismatches.is_empty(), "{indexer:?}: {mismatches:?}");
+            let hash = TorrentFile::from_bytes(&bytes)
+                .expect("should parse")
+                .info_hash_hex();
+            let by_hash = client
+                .get_torrent_by_hash(&hash)
+                .await
+                .expect("should get torrent by hash");
+            assert_eq!(by_hash.torrent.id, server.torrent_id());
+        }
+    }
+
+    #[tokio::test]
+    async fn browse_sets_requested_page() {
+        // Arrange
+        let server = start(MockIndexer::Red);
+        let request = BrowseRequest {
+            page: Some(3),
+            ..BrowseRequest::default()
+        };
+
+        // Act
+        let response = server
+            .client()
+            .browse(&request)
+            .await
+            .expect("should browse");
+
+        // Assert
+        assert_eq!(response.current_page, Some(3));
+        assert!(!response.results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn fixtures_are_linked() {
+        for indexer in INDEXERS {
+            // Arrange
+            let server = start(indexer);
+            let client = server.client();
+
+            // Act
+            let torrent = client
+                .get_torrent(server.torrent_id())
+                .await
+                .expect("should get torrent");
+            let group = client
+                .get_torrent_group(server.group_id())
+                .await
+                .expect("should get group");
+            let browse = client
+                .browse(&BrowseRequest::default())
+                .await
+                .expect("should browse");
+
+            // Assert
+            assert_eq!(torrent.group.id, server.group_id(), "{indexer:?}");
+            assert!(
+                group
+                    .torrents
+                    .iter()
+                    .any(|torrent| torrent.id == server.torrent_id()),
+                "{indexer:?}"
+            );
+            let credit = group
+                .group
+                .music_info
+                .and_then(|info| info.artists.into_iter().next())
+                .expect("group should have an artist");
+            let artist = client
+                .get_artist(ArtistLookup::Name(credit.name))
+                .await
+                .expect("should get artist");
+            assert_eq!(artist.id, credit.id, "{indexer:?}");
+            assert_eq!(artist.torrent_groups[0].group_id, server.group_id());
+            let result = &browse.results[0];
+            assert_eq!(result.group_id, server.group_id(), "{indexer:?}");
+            assert_eq!(result.torrents[0].torrent_id, server.torrent_id());
+        }
+    }
+
+    #[tokio::test]
+    async fn rate_limit_is_retried() {
+        for indexer in INDEXERS {
+            // Arrange
+            let server = start(indexer);
+            server.rate_limit(1);
+            let client = GazelleClient::from(GazelleClientOptions {
+                retry_delays: vec![Duration::from_millis(1)],
+                ..server.options()
+            });
+
+            // Act
+            let response = client.get_index().await;
+
+            // Assert
+            assert_eq!(
+                response.expect("should succeed on retry").id,
+                server.user_id()
+            );
+            assert_eq!(server.requests().len(), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn delay_exceeds_timeout() {
+        // Arrange
+        let server = start(MockIndexer::Ops);
+        server.set_delay(Some(Duration::from_secs(5)));
+        let client = GazelleClient::from(GazelleClientOptions {
+            timeout: Some(Duration::from_millis(100)),
+            ..server.options()
+        });
+
+        // Act
+        let error = client.get_index().await.expect_err("should time out");
+
+        // Assert
+        assert_eq!(error.retry_class(), Some(RetryClass::Transport));
+    }
+
+    #[tokio::test]
+    async fn set_response_replaces_fixture() {
+        // Arrange
+        let server = start(MockIndexer::Red);
+        server.set_response(
+            "index",
+            MockIndexer::Red.failure(401, "This page is limited to API key usage only."),
+        );
+        let client = server.client();
+
+        // Act
+        let error = client.get_index().await.expect_err("should be an error");
+        server.clear_responses();
+        let response = client.get_index().await;
+
+        // Assert
+        assert_eq!(
+            error.operation,
+            GazelleOperation::ApiResponse(ApiResponseKind::Unauthorized)
+        );
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn download_json_without_error() {
+        for indexer in INDEXERS {
+            // Arrange
+            let server = start(indexer);
+            server.set_response("download", indexer.success("[]"));
+            let client = server.client();
+            let path = temp_dir().join(format!("gazelle_api_{indexer:?}_json.torrent"));
+            let mut buffer = Vec::new();
+
+            // Act
+            let to_writer = client
+                .download_torrent_to(server.torrent_id(), &mut buffer)
+                .await
+                .expect_err("should be an error");
+            let to_path = client
+                .download_torrent_to_path(server.torrent_id(), &path)
+                .await
+                .expect_err("should be an error");
+
+            // Assert
+            let expected = GazelleOperation::ApiResponse(ApiResponseKind::Other);
+            assert_eq!(to_writer.operation, expected, "{indexer:?}");
+            assert_eq!(to_path.operation, expected, "{indexer:?}");
+            assert!(buffer.is_empty());
+            assert!(!path.exists(), "{indexer:?}: file should not be created");
+        }
+    }
+
+    #[tokio::test]
+    async fn fill_request_reads_form() {
+        for indexer in INDEXERS {
+            // Arrange
+            let server = start(indexer);
+            let client = server.client();
+            let request_id = client
+                .search_requests(&RequestSearch::default())
+                .await
+                .expect("should search")
+                .results[0]
+                .request_id;
+
+            // Act
+            let filled = client.fill_request(request_id, server.torrent_id()).await;
+            let invalid = client.fill_request(u32::MAX, server.torrent_id()).await;
+
+            // Assert
+            assert_eq!(filled.expect("should fill").request_id, request_id);
+            assert!(invalid.expect_err("should be an error").is_missing());
+        }
+    }
+
+    #[tokio::test]
+    async fn fill_request_already_filled() {
+        for indexer in INDEXERS {
+            // Arrange
+            let server = start(indexer);
+            let failure = indexer.failure(400, "This request has already been filled.");
+            server.set_response("requestfill", failure.clone());
+            server.set_response("torrent", failure);
+            let client = server.client();
+
+            // Act
+            let filled = client
+                .fill_request(1, server.torrent_id())
+                .await
+                .expect_err("should be an error");
+            let other = client
+                .get_torrent(server.torrent_id())
+                .await
+                .expect_err("should be an error");
+
+            // Assert
+            assert_eq!(
+                filled.operation,
+                GazelleOperation::ApiResponse(ApiResponseKind::RequestAlreadyFilled),
+                "{indexer:?}"
+            );
+            assert_ne!(
+                other.operation,
+                GazelleOperation::ApiResponse(ApiResponseKind::RequestAlreadyFilled),
+                "{indexer:?}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn cassette_replays_without_server() {
+        for indexer in INDEXERS {
+            // Arrange
+            let path = temp_dir().join(format!("gazelle_api_cassette_mock_{}", indexer.source()));
+            let _ = remove_dir_all(&path);
+            let server = start(indexer);
+            let options = server.options();
+            let record = GazelleClient::from(GazelleClientOptions {
+                cassette: Some(Cassette::record(path.clone())),
+                ..options.clone()
+            });
+            let recorded = record
+                .get_torrent(server.torrent_id())
+                .await
+                .expect("should get torrent");
+            let bytes = record
+                .download_torrent(server.torrent_id())
+                .await
+                .expect("should download");
+            drop(server);
+            let replay = GazelleClient::from(GazelleClientOptions {
+                cassette: Some(Cassette::replay(path.clone())),
+                ..options
+            });
+
+            // Act
+            let replayed = replay
+                .get_torrent(recorded.torrent.id)
+                .await
+                .expect("should replay torrent");
+            let replayed_bytes = replay
+                .download_torrent(recorded.torrent.id)
+                .await
+                .expect("should replay download");
+            let missing = replay.get_torrent(u32::MAX).await;
+
+            // Assert
+            assert_eq!(replayed.torrent.id, recorded.torrent.id);
+            let hash = |bytes: &[u8]| {
+                TorrentFile::from_bytes(bytes)
+                    .expect("should parse")
+                    .info_hash()
+            };
+            assert_eq!(hash(&replayed_bytes), hash(&bytes));
+            assert_eq!(
+                missing.expect_err("should be an error").operation,
+                GazelleOperation::SendRequest
+            );
+            remove_dir_all(&path).expect("should remove dir");
+        }
+    }
+}
@@ -0,0 +1,13 @@
+pub(crate) use http::*;
+pub(crate) use mock_fixtures::*;
+pub use mock_indexer::*;
+pub use mock_request::*;
+pub use mock_response::*;
+pub use mock_server::*;
+
+mod http;
+mod mock_fixtures;
+mod mock_indexer;
+mod mock_request;
+mod mock_response;
+mod mock_server;
@@ -1,19 +1,35 @@
+pub use crate::adaptive_rate::*;
+pub use crate::cassette::*;
 pub use crate::client::*;
 pub use crate::client_trait::*;
+pub use crate::clock::*;
+pub use crate::cross_seed::*;
 pub use crate::errors::*;
 pub use crate::factory::*;
 #[allow(unused_imports, reason = "RustRover incorrectly flags this as unused")]
 pub(crate) use crate::helpers::*;
+pub use crate::metainfo::*;
+pub use crate::middleware::*;
 #[cfg(feature = "mock")]
 pub use crate::mock::*;
+#[cfg(feature = "mock")]
+pub use crate::mock_server::*;
 pub use crate::options::*;
 pub use crate::rate::*;
+pub use crate::rate_limit_store::*;
 pub use crate::rate_limiter::*;
+pub use crate::rate_limiter_registry::*;
+pub use crate::rate_limiter_stats::*;
+pub use crate::request_priority::*;
+pub use crate::retry_policy::*;
 pub use crate::schema::*;
+#[cfg(feature = "tracing")]
+pub use crate::telemetry::*;
 #[cfg(test)]
 pub(crate) use crate::tests::*;
 
 pub(crate) use async_trait::async_trait;
+pub(crate) use futures::Stream;
 pub(crate) use log::{trace, warn};
 pub(crate) use miette::Diagnostic;
 pub(crate) use reqwest::Error as ReqwestError;
@@ -38,7 +54,7 @@ pub(crate) use std::convert::Infallible;
 pub(crate) use std::error::Error;
 pub(crate) use std::fmt::{Display, Formatter, Result as FmtResult};
 pub(crate) use std::io::Error as IoError;
-pub(crate) use std::path::PathBuf;
+pub(crate) use std::path::{Path, PathBuf};
 pub(crate) use std::str::FromStr;
 #[cfg(test)]
 pub(crate) use std::sync::Arc;
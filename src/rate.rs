@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::time::Duration;
 
 /// Rate limit configuration specifying requests allowed per time window
@@ -7,3 +8,44 @@ pub struct Rate {
     /// Duration before the limit resets.
     pub per: Duration,
 }
+
+impl Rate {
+    /// Get the duration to wait before another request can be made.
+    ///
+    /// - `elapsed` is the time since a request was made
+    /// - Requests older than the rate duration are removed from `requests`
+    ///
+    /// Returns `None` if no wait, else the duration.
+    pub(crate) fn get_wait_duration<T>(
+        &self,
+        requests: &mut VecDeque<T>,
+        elapsed: impl Fn(&T) -> Duration,
+    ) -> Option<Duration> {
+        if requests.len() < self.num {
+            return None;
+        }
+        self.remove_stale(requests, &elapsed);
+        if requests.len() < self.num {
+            return None;
+        }
+        let elapsed = elapsed(requests.front()?);
+        self.per.checked_sub(elapsed)
+    }
+
+    /// Get the number of requests within the rate duration.
+    ///
+    /// - Requests older than the rate duration are removed from `requests`
+    pub(crate) fn get_request_count<T>(
+        &self,
+        requests: &mut VecDeque<T>,
+        elapsed: impl Fn(&T) -> Duration,
+    ) -> usize {
+        self.remove_stale(requests, &elapsed);
+        requests.len()
+    }
+
+    /// Remove requests older than the rate duration.
+    fn remove_stale<T>(&self, requests: &mut VecDeque<T>, elapsed: &impl Fn(&T) -> Duration) {
+        requests.retain(|request| elapsed(request) < self.per);
+    }
+}
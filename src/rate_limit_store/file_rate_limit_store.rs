@@ -0,0 +1,270 @@
+use crate::prelude::*;
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
+use std::time::UNIX_EPOCH;
+use tokio::task::spawn_blocking;
+
+/// A [`RateLimitStore`] that keeps request timestamps in a file.
+///
+/// Every process on the host that uses the same path shares one rate limit, so
+/// several tools can run against the same API key without exceeding it.
+///
+/// - The file is created if it doesn't exist
+/// - An exclusive OS file lock is held while the timestamps are read and written
+/// - Timestamps are stored as milliseconds since the UNIX epoch, one per line
+///
+/// Unlike [`MemoryRateLimitStore`] the timestamps are from the system clock as
+/// monotonic time can't be compared between processes. A timestamp in the future,
+/// for example after the clock is set back, is treated as just made.
+pub struct FileRateLimitStore {
+    path: PathBuf,
+}
+
+impl FileRateLimitStore {
+    /// Create a new [`FileRateLimitStore`]
+    ///
+    /// The file is not opened until the first request.
+    #[must_use]
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Path of the file storing the timestamps.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Run `action` on the timestamps while the file is locked.
+    ///
+    /// - The timestamps are written back only if `action` returns `true`
+    async fn with_requests<T, F>(&self, rate: &Rate, action: F) -> Result<T, IoError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Rate, &mut VecDeque<SystemTime>) -> (T, bool) + Send + 'static,
+    {
+        let path = self.path.clone();
+        let rate = Rate {
+            num: rate.num,
+            per: rate.per,
+        };
+        spawn_blocking(move || {
+            let mut file = open_locked(&path)?;
+            let mut requests = read_requests(&mut file)?;
+            let (value, changed) = action(&rate, &mut requests);
+            if changed {
+                write_requests(&mut file, &requests)?;
+            }
+            file.unlock()?;
+            Ok(value)
+        })
+        .await
+        .map_err(|error| IoError::other(error.to_string()))?
+    }
+}
+
+#[async_trait]
+impl RateLimitStore for FileRateLimitStore {
+    async fn get_wait_duration(&self, rate: &Rate) -> Result<Option<Duration>, IoError> {
+        self.with_requests(rate, |rate, requests| {
+            (rate.get_wait_duration(requests, get_elapsed()), false)
+        })
+        .await
+    }
+
+    async fn get_request_count(&self, rate: &Rate) -> Result<usize, IoError> {
+        self.with_requests(rate, |rate, requests| {
+            (rate.get_request_count(requests, get_elapsed()), false)
+        })
+        .await
+    }
+
+    async fn try_acquire(&self, rate: &Rate) -> Result<Option<Duration>, IoError> {
+        self.with_requests(rate, |rate, requests| {
+            let wait = rate.get_wait_duration(requests, get_elapsed());
+            if wait.is_none() {
+                requests.push_back(SystemTime::now());
+            }
+            // Always write so stale timestamps don't accumulate
+            (wait, true)
+        })
+        .await
+    }
+}
+
+/// Time since a request, or zero if the request is in the future.
+fn get_elapsed() -> impl Fn(&SystemTime) -> Duration {
+    let now = SystemTime::now();
+    move |request| now.duration_since(*request).unwrap_or_default()
+}
+
+/// Open the file, creating it if necessary, and wait for an exclusive lock.
+fn open_locked(path: &Path) -> Result<File, IoError> {
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)?;
+    file.lock()?;
+    Ok(file)
+}
+
+/// Read the timestamps, ignoring any line that isn't a valid timestamp.
+fn read_requests(file: &mut File) -> Result<VecDeque<SystemTime>, IoError> {
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+    let requests = content
+        .lines()
+        .filter_map(|line| line.trim().parse::<u64>().ok())
+        .map(|millis| UNIX_EPOCH + Duration::from_millis(millis))
+        .collect();
+    Ok(requests)
+}
+
+/// Replace the content of the file with the timestamps.
+fn write_requests(file: &mut File, requests: &VecDeque<SystemTime>) -> Result<(), IoError> {
+    let mut content = String::new();
+    for request in requests {
+        let millis = request
+            .duration_since(UNIX_EPOCH)
+            .map_err(|error| IoError::new(ErrorKind::InvalidData, error))?
+            .as_millis();
+        content.push_str(&millis.to_string());
+        content.push('\n');
+    }
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(content.as_bytes())?;
+    file.sync_data()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env::temp_dir;
+    use std::fs::{read_to_string, remove_file, write};
+    use tokio::spawn;
+
+    const RATE: Rate = Rate {
+        num: 3,
+        per: Duration::from_secs(10),
+    };
+
+    fn get_path(name: &str) -> PathBuf {
+        let path = temp_dir().join(format!("gazelle_api_rate_limit_{name}"));
+        let _ = remove_file(&path);
+        path
+    }
+
+    #[tokio::test]
+    async fn try_acquire_creates_file() {
+        // Arrange
+        let path = get_path("creates_file");
+        let store = FileRateLimitStore::new(path.clone());
+
+        // Act
+        let wait = store.try_acquire(&RATE).await.expect("should acquire");
+
+        // Assert
+        assert!(wait.is_none());
+        let content = read_to_string(&path).expect("file should exist");
+        assert_eq!(content.lines().count(), 1);
+        remove_file(&path).expect("should remove file");
+    }
+
+    #[tokio::test]
+    async fn try_acquire_shared_between_stores() {
+        // Arrange
+        let path = get_path("shared");
+        let first = FileRateLimitStore::new(path.clone());
+        let second = FileRateLimitStore::new(path.clone());
+
+        // Act
+        for _ in 0..RATE.num {
+            let wait = first.try_acquire(&RATE).await.expect("should acquire");
+            assert!(wait.is_none());
+        }
+        let wait = second.try_acquire(&RATE).await.expect("should acquire");
+
+        // Assert
+        let wait = wait.expect("second store should see the first store's requests");
+        assert!(wait <= RATE.per);
+        assert!(wait > Duration::from_secs(9));
+        assert_eq!(
+            read_to_string(&path)
+                .expect("file should exist")
+                .lines()
+                .count(),
+            RATE.num
+        );
+        remove_file(&path).expect("should remove file");
+    }
+
+    #[tokio::test]
+    async fn try_acquire_concurrent_never_exceeds_rate() {
+        // Arrange
+        let path = get_path("concurrent");
+        let tasks: Vec<_> = (0..10)
+            .map(|_| {
+                let store = FileRateLimitStore::new(path.clone());
+                spawn(async move { store.try_acquire(&RATE).await })
+            })
+            .collect();
+
+        // Act
+        let mut acquired = 0;
+        for task in tasks {
+            let wait = task
+                .await
+                .expect("task should not panic")
+                .expect("should acquire");
+            if wait.is_none() {
+                acquired += 1;
+            }
+        }
+
+        // Assert
+        assert_eq!(acquired, RATE.num);
+        remove_file(&path).expect("should remove file");
+    }
+
+    #[tokio::test]
+    async fn try_acquire_removes_stale_and_invalid() {
+        // Arrange
+        let path = get_path("stale");
+        let stale = SystemTime::now() - Duration::from_mins(1);
+        let millis = stale
+            .duration_since(UNIX_EPOCH)
+            .expect("should be after epoch")
+            .as_millis();
+        write(&path, format!("{millis}\n{millis}\n{millis}\ninvalid\n")).expect("should write");
+        let store = FileRateLimitStore::new(path.clone());
+
+        // Act
+        let wait = store.try_acquire(&RATE).await.expect("should acquire");
+
+        // Assert
+        assert!(wait.is_none());
+        let content = read_to_string(&path).expect("file should exist");
+        assert_eq!(content.lines().count(), 1);
+        assert!(!content.contains(&millis.to_string()));
+        remove_file(&path).expect("should remove file");
+    }
+
+    #[tokio::test]
+    async fn get_wait_duration_does_not_record() {
+        // Arrange
+        let path = get_path("wait_duration");
+        let store = FileRateLimitStore::new(path.clone());
+
+        // Act
+        let wait = store.get_wait_duration(&RATE).await.expect("should read");
+
+        // Assert
+        assert!(wait.is_none());
+        assert_eq!(read_to_string(&path).expect("file should exist"), "");
+        remove_file(&path).expect("should remove file");
+    }
+}
@@ -0,0 +1,60 @@
+use crate::prelude::*;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// A [`RateLimitStore`] that keeps request timestamps in memory.
+///
+/// The default store. Only requests made through the same store are counted.
+///
+/// Timestamps come from a monotonic [`Clock`] so adjusting the system clock
+/// has no effect.
+pub struct MemoryRateLimitStore {
+    pub(crate) requests: Mutex<VecDeque<Instant>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl Default for MemoryRateLimitStore {
+    fn default() -> Self {
+        Self::with_clock(Arc::new(TokioClock))
+    }
+}
+
+impl MemoryRateLimitStore {
+    /// Create a new [`MemoryRateLimitStore`] with the given [`Clock`]
+    #[must_use]
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            requests: Mutex::new(VecDeque::new()),
+            clock,
+        }
+    }
+
+    fn get_elapsed(&self) -> impl Fn(&Instant) -> Duration {
+        let now = self.clock.now();
+        move |request| now.saturating_duration_since(*request)
+    }
+}
+
+#[async_trait]
+impl RateLimitStore for MemoryRateLimitStore {
+    async fn get_wait_duration(&self, rate: &Rate) -> Result<Option<Duration>, IoError> {
+        let mut requests = self.requests.lock().await;
+        Ok(rate.get_wait_duration(&mut requests, self.get_elapsed()))
+    }
+
+    async fn get_request_count(&self, rate: &Rate) -> Result<usize, IoError> {
+        let mut requests = self.requests.lock().await;
+        Ok(rate.get_request_count(&mut requests, self.get_elapsed()))
+    }
+
+    async fn try_acquire(&self, rate: &Rate) -> Result<Option<Duration>, IoError> {
+        let mut requests = self.requests.lock().await;
+        let wait = rate.get_wait_duration(&mut requests, self.get_elapsed());
+        if wait.is_none() {
+            requests.push_back(self.clock.now());
+        }
+        Ok(wait)
+    }
+}
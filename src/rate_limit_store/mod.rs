@@ -0,0 +1,7 @@
+pub use file_rate_limit_store::*;
+pub use memory_rate_limit_store::*;
+pub use rate_limit_store::*;
+
+mod file_rate_limit_store;
+mod memory_rate_limit_store;
+mod rate_limit_store;
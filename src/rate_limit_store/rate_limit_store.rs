@@ -0,0 +1,25 @@
+use crate::prelude::*;
+
+/// Storage for the timestamps of recent requests used by a [`RateLimiter`].
+///
+/// - [`MemoryRateLimitStore`] is private to one process
+/// - [`FileRateLimitStore`] is shared by every process using the same file
+#[async_trait]
+pub trait RateLimitStore: Send + Sync {
+    /// Get the duration to wait before a request can be made.
+    ///
+    /// Returns `None` if no wait, else the duration.
+    async fn get_wait_duration(&self, rate: &Rate) -> Result<Option<Duration>, IoError>;
+
+    /// Get the number of requests made within the current window.
+    async fn get_request_count(&self, rate: &Rate) -> Result<usize, IoError>;
+
+    /// Record a request if the rate allows it.
+    ///
+    /// The check and the record must be atomic so concurrent callers can't both
+    /// take the last slot.
+    ///
+    /// Returns `None` if the request was recorded, else the duration to wait
+    /// before trying again.
+    async fn try_acquire(&self, rate: &Rate) -> Result<Option<Duration>, IoError>;
+}
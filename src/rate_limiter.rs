@@ -1,75 +1,321 @@
 use crate::prelude::*;
 use std::collections::VecDeque;
-use std::time::{Duration, SystemTime};
-use tokio::sync::Mutex;
-use tokio::time::sleep;
+use std::pin::pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::time::{Instant, sleep};
+
+/// Maximum number of interactive requests given a slot in a row while a
+/// background request is waiting.
+const MAX_INTERACTIVE_STREAK: usize = 4;
 
 /// A sliding window rate limiter for throttling API requests.
 ///
-/// Tracks request timestamps in a queue and enforces a maximum number of
-/// requests within a sliding time window. When the limit is reached, callers
-/// are delayed until the oldest request falls outside the window.
+/// Tracks request timestamps in a [`RateLimitStore`] and enforces a maximum
+/// number of requests within a sliding time window. When the limit is reached,
+/// callers are delayed until the oldest request falls outside the window.
+///
+/// The default [`MemoryRateLimitStore`] only counts requests made through this
+/// limiter. Use [`RateLimiter::with_store`] and a [`FileRateLimitStore`] to share
+/// the limit with other processes.
+///
+/// Wrap in an [`Arc`] and pass to [`GazelleClientFactory::limiter`] to share
+/// one limiter between several clients.
 ///
-/// Uses interior mutability via [`tokio::sync::Mutex`] to allow shared
-/// access (`&self`) across async tasks. Locks are held only for brief
-/// in-memory operations, never across await points or sleeps.
+/// Waiting callers are queued in two lanes by [`RequestPriority`]. Only the
+/// caller at the head of the queue tries to take a slot, so a free slot goes to
+/// the oldest interactive caller, unless 4 interactive callers in a row have
+/// been served while a background caller was waiting.
+///
+/// With [`RateLimiter::with_adaptive`] the rate is reduced when the indexer
+/// responds `429 Too Many Requests` and recovers after a run of successes.
 pub struct RateLimiter {
     pub(crate) rate: Rate,
-    pub(crate) requests: Mutex<VecDeque<SystemTime>>,
+    pub(crate) store: Arc<dyn RateLimitStore>,
+    lanes: Mutex<Lanes>,
+    notify: Notify,
+    adaptive: Option<AdaptiveRate>,
+    adaptive_state: Mutex<AdaptiveState>,
+    clock: Arc<dyn Clock>,
 }
 
 impl RateLimiter {
-    /// Create a new [`RateLimiter`]
+    /// Create a new [`RateLimiter`] with a [`MemoryRateLimitStore`]
     #[must_use]
     pub fn new(num: usize, per: Duration) -> Self {
+        Self::with_clock(num, per, Arc::new(TokioClock))
+    }
+
+    /// Create a new [`RateLimiter`] with the given [`RateLimitStore`]
+    #[must_use]
+    pub fn with_store(num: usize, per: Duration, store: Arc<dyn RateLimitStore>) -> Self {
+        Self::create(num, per, store, Arc::new(TokioClock))
+    }
+
+    /// Create a new [`RateLimiter`] with a [`MemoryRateLimitStore`] and the given [`Clock`]
+    #[must_use]
+    pub fn with_clock(num: usize, per: Duration, clock: Arc<dyn Clock>) -> Self {
+        let store = Arc::new(MemoryRateLimitStore::with_clock(clock.clone()));
+        Self::create(num, per, store, clock)
+    }
+
+    fn create(
+        num: usize,
+        per: Duration,
+        store: Arc<dyn RateLimitStore>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
         Self {
             rate: Rate { num, per },
-            requests: Mutex::new(VecDeque::new()),
+            store,
+            lanes: Mutex::new(Lanes::default()),
+            notify: Notify::new(),
+            adaptive: None,
+            adaptive_state: Mutex::new(AdaptiveState {
+                num,
+                successes: 0,
+                reduced_at: None,
+            }),
+            clock,
+        }
+    }
+
+    /// Adapt the rate to `429 Too Many Requests` responses within the given bounds.
+    #[must_use]
+    pub fn with_adaptive(mut self, adaptive: AdaptiveRate) -> Self {
+        self.adaptive = Some(adaptive);
+        self
+    }
+
+    /// Get the rate currently enforced.
+    ///
+    /// Lower than the configured rate while adaptive rate limiting has backed off.
+    #[must_use]
+    pub fn get_rate(&self) -> Rate {
+        Rate {
+            num: self.lock_adaptive_state().num,
+            per: self.rate.per,
+        }
+    }
+
+    /// Record a `429 Too Many Requests` response.
+    ///
+    /// Halves the rate if adaptive and it wasn't already reduced within the last duration.
+    pub fn record_rate_limited(&self) {
+        let Some(adaptive) = self.adaptive else {
+            return;
+        };
+        let mut state = self.lock_adaptive_state();
+        state.successes = 0;
+        let now = self.clock.now();
+        if state
+            .reduced_at
+            .is_some_and(|reduced_at| now.saturating_duration_since(reduced_at) < self.rate.per)
+        {
+            return;
+        }
+        let num = state.num.div_euclid(2).max(adaptive.min_num).max(1);
+        if num < state.num {
+            warn!(
+                "Rate limited by indexer, reducing rate to {num} per {:.1}s",
+                self.rate.per.as_secs_f64()
+            );
+            state.num = num;
+            state.reduced_at = Some(now);
+        }
+    }
+
+    /// Record a successful response.
+    ///
+    /// Increases the rate by one if adaptive and enough successes were recorded in a row.
+    pub fn record_success(&self) {
+        let Some(adaptive) = self.adaptive else {
+            return;
+        };
+        let mut state = self.lock_adaptive_state();
+        if state.num >= self.rate.num {
+            return;
+        }
+        state.successes += 1;
+        if state.successes >= adaptive.recover_after {
+            state.num += 1;
+            state.successes = 0;
+            trace!(
+                "Increasing rate to {} per {:.1}s",
+                state.num,
+                self.rate.per.as_secs_f64()
+            );
         }
     }
 
     /// Wait if the rate limit requires a delay, then record the request.
     ///
+    /// - Callers are served by [`RequestPriority`], then in order of arrival
+    /// - If the store fails the request is not throttled and a warning is logged
+    ///
     /// Returns `None` if there was no wait, otherwise returns the wait duration.
-    pub async fn execute(&self) -> Option<Duration> {
-        let wait_duration = self.get_wait_duration().await;
-        if let Some(wait) = wait_duration {
-            trace!("Waiting {:.3} for rate limiter", wait.as_secs_f64());
-            sleep(wait).await;
-            self.requests.lock().await.pop_front();
+    pub async fn execute(&self, priority: RequestPriority) -> Option<Duration> {
+        let ticket = Ticket::new(self, priority);
+        let start = self.clock.now();
+        let mut waited = false;
+        loop {
+            let mut notified = pin!(self.notify.notified());
+            notified.as_mut().enable();
+            if !ticket.is_next() {
+                trace!("Waiting in {priority:?} lane for rate limiter");
+                waited = true;
+                notified.await;
+                continue;
+            }
+            match self.store.try_acquire(&self.get_rate()).await {
+                Ok(None) => break,
+                Ok(Some(wait)) => {
+                    trace!("Waiting {:.3} for rate limiter", wait.as_secs_f64());
+                    waited = true;
+                    sleep(wait).await;
+                }
+                Err(error) => {
+                    warn!("Failed to update rate limit store: {error}");
+                    break;
+                }
+            }
         }
-        self.requests.lock().await.push_back(SystemTime::now());
-        wait_duration
+        ticket.complete();
+        waited.then(|| self.clock.now().saturating_duration_since(start))
     }
 
     /// Get the duration to wait before a request can be made.
     ///
-    /// Returns `None` if no wait, else the duration.
+    /// Returns `None` if no wait or the store failed, else the duration.
     pub async fn get_wait_duration(&self) -> Option<Duration> {
-        let mut requests = self.requests.lock().await;
-        if requests.len() < self.rate.num {
-            return None;
+        match self.store.get_wait_duration(&self.get_rate()).await {
+            Ok(wait) => wait,
+            Err(error) => {
+                warn!("Failed to read rate limit store: {error}");
+                None
+            }
+        }
+    }
+
+    /// Get a snapshot of the state of the rate limiter.
+    ///
+    /// - `requests` is `0` if the store failed
+    pub async fn stats(&self) -> RateLimiterStats {
+        let rate = self.get_rate();
+        let requests = match self.store.get_request_count(&rate).await {
+            Ok(count) => count,
+            Err(error) => {
+                warn!("Failed to read rate limit store: {error}");
+                0
+            }
+        };
+        let lanes = self.lock_lanes();
+        RateLimiterStats {
+            waiting: lanes.interactive.len() + lanes.background.len(),
+            waiting_background: lanes.background.len(),
+            requests,
+            num: rate.num,
+            per: rate.per,
         }
-        Self::remove_stale(&mut requests, self.rate.per);
-        if requests.len() < self.rate.num {
-            return None;
+    }
+
+    fn lock_lanes(&self) -> MutexGuard<'_, Lanes> {
+        self.lanes
+            .lock()
+            .expect("rate limiter lanes lock should not be poisoned")
+    }
+
+    fn lock_adaptive_state(&self) -> MutexGuard<'_, AdaptiveState> {
+        self.adaptive_state
+            .lock()
+            .expect("rate limiter adaptive state lock should not be poisoned")
+    }
+}
+
+/// Rate currently enforced by an adaptive [`RateLimiter`].
+struct AdaptiveState {
+    num: usize,
+    /// Successful responses in a row since the rate last changed.
+    successes: usize,
+    /// When the rate was last reduced.
+    reduced_at: Option<Instant>,
+}
+
+/// Queue of callers waiting for the rate limiter.
+#[derive(Default)]
+struct Lanes {
+    interactive: VecDeque<u64>,
+    background: VecDeque<u64>,
+    next_id: u64,
+    /// Interactive callers served in a row while a background caller was waiting.
+    streak: usize,
+}
+
+impl Lanes {
+    fn push(&mut self, priority: RequestPriority) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        match priority {
+            RequestPriority::Interactive => self.interactive.push_back(id),
+            RequestPriority::Background => self.background.push_back(id),
         }
-        let request = requests.front()?;
-        let elapsed = request.elapsed().expect("elapsed should not fail");
-        if elapsed > self.rate.per {
-            return None;
+        id
+    }
+
+    /// The caller that should take the next free slot.
+    fn next(&self) -> Option<u64> {
+        let background = self.background.front();
+        if self.streak >= MAX_INTERACTIVE_STREAK && background.is_some() {
+            return background.copied();
         }
-        Some(
-            self.rate
-                .per
-                .checked_sub(elapsed)
-                .expect("duration should not overflow"),
-        )
-    }
-
-    /// Remove requests older than the rate duration.
-    fn remove_stale(requests: &mut VecDeque<SystemTime>, per: Duration) {
-        let cutoff = SystemTime::now() - per;
-        requests.retain(|&request| request > cutoff);
+        self.interactive.front().or(background).copied()
+    }
+
+    fn remove(&mut self, id: u64) {
+        self.interactive.retain(|&other| other != id);
+        self.background.retain(|&other| other != id);
+    }
+}
+
+/// Place of a caller in the [`Lanes`].
+///
+/// Removed from the queue when dropped so a cancelled request doesn't block
+/// the callers behind it.
+struct Ticket<'a> {
+    limiter: &'a RateLimiter,
+    id: u64,
+    priority: RequestPriority,
+}
+
+impl<'a> Ticket<'a> {
+    fn new(limiter: &'a RateLimiter, priority: RequestPriority) -> Self {
+        let id = limiter.lock_lanes().push(priority);
+        Self {
+            limiter,
+            id,
+            priority,
+        }
+    }
+
+    fn is_next(&self) -> bool {
+        self.limiter.lock_lanes().next() == Some(self.id)
+    }
+
+    /// Record that the caller was given a slot.
+    fn complete(self) {
+        let mut lanes = self.limiter.lock_lanes();
+        match self.priority {
+            RequestPriority::Interactive if !lanes.background.is_empty() => lanes.streak += 1,
+            RequestPriority::Interactive => {}
+            RequestPriority::Background => lanes.streak = 0,
+        }
+    }
+}
+
+impl Drop for Ticket<'_> {
+    fn drop(&mut self) {
+        self.limiter.lock_lanes().remove(self.id);
+        self.limiter.notify.notify_waiters();
     }
 }
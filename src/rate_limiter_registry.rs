@@ -0,0 +1,111 @@
+use crate::prelude::*;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+static GLOBAL: OnceLock<RateLimiterRegistry> = OnceLock::new();
+
+/// Shares one [`RateLimiter`] between every client for the same account.
+///
+/// Limiters are keyed by the base URL and API key of the options.
+///
+/// - The first options for an account decide the rate, later options only look it up
+/// - Limiters are kept for the lifetime of the registry
+#[derive(Default)]
+pub struct RateLimiterRegistry {
+    limiters: Mutex<HashMap<(String, String), Arc<RateLimiter>>>,
+}
+
+impl RateLimiterRegistry {
+    /// Create a new empty [`RateLimiterRegistry`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry shared by the whole process.
+    #[must_use]
+    pub fn global() -> &'static Self {
+        GLOBAL.get_or_init(Self::new)
+    }
+
+    /// Get the [`RateLimiter`] for the account, creating it from `options` if needed.
+    #[must_use]
+    pub fn get_or_create(&self, options: &GazelleClientOptions) -> Arc<RateLimiter> {
+        let key = (
+            options.url.trim_end_matches('/').to_owned(),
+            options.key.clone(),
+        );
+        let mut limiters = self
+            .limiters
+            .lock()
+            .expect("rate limiter registry lock should not be poisoned");
+        limiters
+            .entry(key)
+            .or_insert_with(|| Arc::new(RateLimiter::from(options)))
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(url: &str, key: &str) -> GazelleClientOptions {
+        GazelleClientOptions {
+            key: key.to_owned(),
+            ..get_test_options(url)
+        }
+    }
+
+    #[test]
+    fn get_or_create_same_account() {
+        // Arrange
+        let registry = RateLimiterRegistry::new();
+
+        // Act
+        let first = registry.get_or_create(&options("https://example.com", "a"));
+        let second = registry.get_or_create(&options("https://example.com/", "a"));
+
+        // Assert
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn get_or_create_different_accounts() {
+        // Arrange
+        let registry = RateLimiterRegistry::new();
+
+        // Act
+        let first = registry.get_or_create(&options("https://example.com", "a"));
+        let other_key = registry.get_or_create(&options("https://example.com", "b"));
+        let other_url = registry.get_or_create(&options("https://example.org", "a"));
+
+        // Assert
+        assert!(!Arc::ptr_eq(&first, &other_key));
+        assert!(!Arc::ptr_eq(&first, &other_url));
+    }
+
+    #[tokio::test]
+    async fn get_or_create_shares_window() {
+        // Arrange
+        let registry = RateLimiterRegistry::new();
+        let first = GazelleClientFactory {
+            limiter: Some(registry.get_or_create(&options("https://example.com", "a"))),
+            options: options("https://example.com", "a"),
+            middleware: Vec::new(),
+        }
+        .create();
+        let second = GazelleClientFactory {
+            limiter: Some(registry.get_or_create(&options("https://example.com", "a"))),
+            options: options("https://example.com", "a"),
+            middleware: Vec::new(),
+        }
+        .create();
+
+        // Act
+        first.limiter.execute(RequestPriority::Interactive).await;
+
+        // Assert
+        assert_eq!(second.limiter.stats().await.requests, 1);
+    }
+}
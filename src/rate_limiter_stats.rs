@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+/// Snapshot of the state of a [`RateLimiter`](crate::RateLimiter)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RateLimiterStats {
+    /// Number of requests currently waiting for the rate limiter.
+    pub waiting: usize,
+    /// Number of waiting requests with [`RequestPriority::Background`](crate::RequestPriority::Background).
+    pub waiting_background: usize,
+    /// Number of requests made within the current window.
+    ///
+    /// Includes requests from other limiters sharing the same store.
+    pub requests: usize,
+    /// Number of requests currently allowed per duration.
+    ///
+    /// Lower than configured while adaptive rate limiting has backed off.
+    pub num: usize,
+    /// Duration before the limit resets.
+    pub per: Duration,
+}
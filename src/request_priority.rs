@@ -0,0 +1,15 @@
+use crate::prelude::*;
+
+/// Priority of a request waiting for the [`RateLimiter`].
+///
+/// When a slot frees up it goes to the oldest waiting interactive request, unless
+/// background requests have already been passed over too many times in a row.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequestPriority {
+    /// A request someone is waiting on, such as a lookup from a UI.
+    #[default]
+    Interactive,
+    /// A request that can be delayed, such as a page of a long crawl.
+    Background,
+}
@@ -0,0 +1,394 @@
+use crate::prelude::*;
+use fastrand::f64 as random_f64;
+
+/// Default upper limit for a delay requested by a `Retry-After` header.
+const DEFAULT_MAX_RETRY_AFTER: Duration = Duration::from_mins(5);
+
+/// Strategy for retrying transient failures.
+///
+/// - `retry_on` decides which [`RetryClass`] of error is retried
+/// - The [`Backoff`] decides how many retries are made and how long to wait between them
+/// - A `Retry-After` header sent by the indexer replaces the backoff delay, up to `max_retry_after`
+/// - `max_elapsed` stops retrying once the next attempt would start too late
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RetryPolicy {
+    /// Delays between attempts.
+    pub backoff: Backoff,
+    /// Maximum time from the first attempt to the start of a retry.
+    ///
+    /// Default: no limit
+    #[serde(default)]
+    pub max_elapsed: Option<Duration>,
+    /// Upper limit for a delay requested by a `Retry-After` header.
+    ///
+    /// Longer delays are shortened to this so an indexer can't stall the client.
+    ///
+    /// Default: 5 minutes
+    #[serde(default = "default_max_retry_after")]
+    pub max_retry_after: Duration,
+    /// Classes of error to retry.
+    ///
+    /// - Use [`RetryClass::ALL`] to also retry server and transport errors, but only if
+    ///   repeating a request that may have reached the indexer is safe
+    ///
+    /// Default: [`RetryClass::RateLimited`]
+    #[serde(default = "default_retry_on")]
+    pub retry_on: Vec<RetryClass>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            backoff: Backoff::default(),
+            max_elapsed: None,
+            max_retry_after: DEFAULT_MAX_RETRY_AFTER,
+            retry_on: default_retry_on(),
+        }
+    }
+}
+
+/// Delays between retry attempts.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Backoff {
+    /// Fixed list of delays.
+    ///
+    /// `delays[i]` is the wait before retry `i + 1`. Empty disables retry.
+    Fixed {
+        /// Delays between attempts
+        delays: Vec<Duration>,
+    },
+    /// Exponential backoff with jitter.
+    ///
+    /// The delay before retry `n` is `initial * 2^(n - 1)` capped at `max`, then
+    /// randomly reduced by up to half so concurrent clients spread out.
+    Exponential {
+        /// Delay before the first retry
+        initial: Duration,
+        /// Upper limit for any delay
+        max: Duration,
+        /// Number of retries after the initial attempt
+        retries: usize,
+    },
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self::Fixed { delays: Vec::new() }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a [`RetryPolicy`] that never retries.
+    #[must_use]
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Create a [`RetryPolicy`] with a fixed list of delays.
+    #[must_use]
+    pub fn fixed(delays: Vec<Duration>) -> Self {
+        Self {
+            backoff: Backoff::Fixed { delays },
+            ..Self::default()
+        }
+    }
+
+    /// Create a [`RetryPolicy`] with exponential backoff and jitter.
+    #[must_use]
+    pub fn exponential(initial: Duration, max: Duration, retries: usize) -> Self {
+        Self {
+            backoff: Backoff::Exponential {
+                initial,
+                max,
+                retries,
+            },
+            ..Self::default()
+        }
+    }
+
+    /// Set the maximum time from the first attempt to the start of a retry.
+    #[must_use]
+    pub fn with_max_elapsed(mut self, max_elapsed: Duration) -> Self {
+        self.max_elapsed = Some(max_elapsed);
+        self
+    }
+
+    /// Set the upper limit for a delay requested by a `Retry-After` header.
+    #[must_use]
+    pub fn with_max_retry_after(mut self, max_retry_after: Duration) -> Self {
+        self.max_retry_after = max_retry_after;
+        self
+    }
+
+    /// Set the classes of error to retry.
+    #[must_use]
+    pub fn with_retry_on(mut self, retry_on: Vec<RetryClass>) -> Self {
+        self.retry_on = retry_on;
+        self
+    }
+
+    /// Whether the error is in a class this policy retries.
+    #[must_use]
+    pub fn should_retry(&self, error: &GazelleError) -> bool {
+        error
+            .retry_class()
+            .is_some_and(|class| self.retry_on.contains(&class))
+    }
+
+    /// Total number of attempts including the initial request.
+    #[must_use]
+    pub fn max_attempts(&self) -> usize {
+        match &self.backoff {
+            Backoff::Fixed { delays } => delays.len() + 1,
+            Backoff::Exponential { retries, .. } => retries + 1,
+        }
+    }
+
+    /// Get the delay before the next attempt, or `None` if the caller should give up.
+    ///
+    /// - `attempt` is 0-indexed: attempt 0 is the initial request
+    /// - `retry_after` is the delay requested by the indexer, if any, capped at `max_retry_after`
+    /// - `elapsed` is the time since the initial request started
+    #[must_use]
+    pub fn get_delay(
+        &self,
+        attempt: usize,
+        retry_after: Option<Duration>,
+        elapsed: Duration,
+    ) -> Option<Duration> {
+        let delay = match &self.backoff {
+            Backoff::Fixed { delays } => delays.get(attempt).copied()?,
+            Backoff::Exponential {
+                initial,
+                max,
+                retries,
+            } => {
+                if attempt >= *retries {
+                    return None;
+                }
+                apply_jitter(get_exponential_delay(*initial, *max, attempt), random_f64())
+            }
+        };
+        let delay = retry_after.map_or(delay, |retry_after| retry_after.min(self.max_retry_after));
+        if let Some(max_elapsed) = self.max_elapsed
+            && elapsed + delay > max_elapsed
+        {
+            return None;
+        }
+        Some(delay)
+    }
+}
+
+fn default_max_retry_after() -> Duration {
+    DEFAULT_MAX_RETRY_AFTER
+}
+
+fn default_retry_on() -> Vec<RetryClass> {
+    vec![RetryClass::RateLimited]
+}
+
+/// Delay before the retry following `attempt`, without jitter.
+fn get_exponential_delay(initial: Duration, max: Duration, attempt: usize) -> Duration {
+    u32::try_from(attempt)
+        .ok()
+        .and_then(|attempt| 2_u32.checked_pow(attempt))
+        .and_then(|factor| initial.checked_mul(factor))
+        .map_or(max, |delay| delay.min(max))
+}
+
+/// Reduce `delay` by up to half.
+///
+/// `random` is in the range `0.0..1.0`.
+fn apply_jitter(delay: Duration, random: f64) -> Duration {
+    delay.mul_f64(0.5 + random * 0.5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NO_TIME: Duration = Duration::ZERO;
+
+    #[test]
+    fn fixed_within_bounds() {
+        let policy = RetryPolicy::fixed(vec![Duration::from_secs(5), Duration::from_secs(10)]);
+        assert_eq!(
+            policy.get_delay(0, None, NO_TIME),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            policy.get_delay(1, None, NO_TIME),
+            Some(Duration::from_secs(10))
+        );
+    }
+
+    #[test]
+    fn fixed_exhausted() {
+        let policy = RetryPolicy::fixed(vec![Duration::from_secs(5), Duration::from_secs(10)]);
+        assert_eq!(policy.get_delay(2, None, NO_TIME), None);
+    }
+
+    #[test]
+    fn fixed_empty() {
+        let policy = RetryPolicy::none();
+        assert_eq!(policy.get_delay(0, None, NO_TIME), None);
+        assert_eq!(policy.max_attempts(), 1);
+    }
+
+    #[test]
+    fn retry_after_replaces_delay() {
+        let policy = RetryPolicy::fixed(vec![Duration::from_secs(5)]);
+        assert_eq!(
+            policy.get_delay(0, Some(Duration::from_secs(30)), NO_TIME),
+            Some(Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn retry_after_capped() {
+        let policy = RetryPolicy::fixed(vec![Duration::from_secs(5)]);
+        assert_eq!(
+            policy.get_delay(0, Some(Duration::from_hours(24)), NO_TIME),
+            Some(DEFAULT_MAX_RETRY_AFTER)
+        );
+        let policy = policy.with_max_retry_after(Duration::from_secs(10));
+        assert_eq!(
+            policy.get_delay(0, Some(Duration::from_hours(24)), NO_TIME),
+            Some(Duration::from_secs(10))
+        );
+    }
+
+    #[test]
+    fn retry_after_does_not_extend_retries() {
+        let policy = RetryPolicy::fixed(vec![Duration::from_secs(5)]);
+        assert_eq!(
+            policy.get_delay(1, Some(Duration::from_secs(1)), NO_TIME),
+            None
+        );
+    }
+
+    #[test]
+    fn max_elapsed_exceeded() {
+        // Arrange
+        let policy = RetryPolicy::fixed(vec![Duration::from_secs(5), Duration::from_secs(10)])
+            .with_max_elapsed(Duration::from_secs(12));
+
+        // Act & Assert
+        assert_eq!(
+            policy.get_delay(0, None, Duration::from_secs(1)),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(policy.get_delay(1, None, Duration::from_secs(6)), None);
+    }
+
+    #[test]
+    fn max_elapsed_applies_to_retry_after() {
+        let policy = RetryPolicy::fixed(vec![Duration::from_secs(5)])
+            .with_max_elapsed(Duration::from_mins(1));
+        assert_eq!(
+            policy.get_delay(0, Some(Duration::from_mins(2)), NO_TIME),
+            None
+        );
+    }
+
+    #[test]
+    fn exponential_delay_doubles() {
+        let initial = Duration::from_secs(1);
+        let max = Duration::from_mins(1);
+        assert_eq!(
+            get_exponential_delay(initial, max, 0),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            get_exponential_delay(initial, max, 1),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            get_exponential_delay(initial, max, 3),
+            Duration::from_secs(8)
+        );
+    }
+
+    #[test]
+    fn exponential_delay_capped() {
+        let initial = Duration::from_secs(1);
+        let max = Duration::from_mins(1);
+        assert_eq!(get_exponential_delay(initial, max, 10), max);
+        assert_eq!(get_exponential_delay(initial, max, 100), max);
+    }
+
+    #[test]
+    fn jitter_bounds() {
+        let delay = Duration::from_secs(10);
+        assert_eq!(apply_jitter(delay, 0.0), Duration::from_secs(5));
+        assert!(apply_jitter(delay, 0.999) <= delay);
+    }
+
+    #[test]
+    fn exponential_within_jitter_range() {
+        // Arrange
+        let policy = RetryPolicy::exponential(Duration::from_secs(2), Duration::from_mins(1), 3);
+
+        // Act & Assert
+        for attempt in 0..3 {
+            let ceiling =
+                get_exponential_delay(Duration::from_secs(2), Duration::from_mins(1), attempt);
+            let delay = policy
+                .get_delay(attempt, None, NO_TIME)
+                .expect("should retry");
+            assert!(delay >= ceiling / 2 && delay <= ceiling);
+        }
+        assert_eq!(policy.get_delay(3, None, NO_TIME), None);
+        assert_eq!(policy.max_attempts(), 4);
+    }
+
+    #[test]
+    fn should_retry_default_classes() {
+        let policy = RetryPolicy::fixed(vec![Duration::from_secs(5)]);
+        assert!(policy.should_retry(&GazelleError::too_many_requests(String::new(), 429)));
+        assert!(!policy.should_retry(&GazelleError::bad_gateway(String::new(), 502)));
+        assert!(!policy.should_retry(&GazelleError::not_found(String::new(), 404)));
+    }
+
+    #[test]
+    fn should_retry_configured_classes() {
+        let policy = RetryPolicy::none().with_retry_on(RetryClass::ALL.to_vec());
+        assert!(policy.should_retry(&GazelleError::too_many_requests(String::new(), 429)));
+        assert!(policy.should_retry(&GazelleError::gateway_timeout(String::new(), 504)));
+        assert!(!policy.should_retry(&GazelleError::not_found(String::new(), 404)));
+    }
+
+    #[test]
+    fn deserialize_exponential() {
+        // Arrange
+        let yaml = r"
+backoff:
+  type: exponential
+  initial:
+    secs: 1
+    nanos: 0
+  max:
+    secs: 30
+    nanos: 0
+  retries: 4
+max_elapsed:
+  secs: 120
+  nanos: 0
+retry_on:
+- rate_limited
+- server_error
+";
+
+        // Act
+        let policy: RetryPolicy = yaml_from_str(yaml).expect("should deserialize");
+
+        // Assert
+        assert_eq!(
+            policy,
+            RetryPolicy::exponential(Duration::from_secs(1), Duration::from_secs(30), 4)
+                .with_max_elapsed(Duration::from_mins(2))
+                .with_retry_on(vec![RetryClass::RateLimited, RetryClass::ServerError])
+        );
+    }
+}
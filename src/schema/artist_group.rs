@@ -0,0 +1,84 @@
+use crate::prelude::*;
+
+/// A torrent group entry in an [`ArtistResponse`].
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtistGroup {
+    /// Group ID
+    pub group_id: u32,
+    /// Group (album) name
+    #[serde(deserialize_with = "decode_entities")]
+    pub group_name: String,
+    /// Release year
+    pub group_year: u16,
+    /// Record label
+    #[serde(deserialize_with = "decode_entities")]
+    pub group_record_label: String,
+    /// Catalogue number
+    #[serde(deserialize_with = "decode_entities")]
+    pub group_catalogue_number: String,
+    /// Tag names
+    pub tags: Vec<String>,
+    /// Release type
+    pub release_type: ReleaseTypeId,
+    /// Is this a Vanity House release?
+    pub group_vanity_house: bool,
+    /// Is this group bookmarked by the authenticated user?
+    pub has_bookmarked: bool,
+    /// Cover image URL
+    ///
+    /// *OPS only*
+    pub wiki_image: Option<String>,
+    /// Editions in the group.
+    ///
+    /// - Referred to as `torrent` in the API response
+    /// - Fields only present in the `torrent` and `torrentgroup` actions are left at their defaults
+    #[serde(rename = "torrent")]
+    pub torrents: Vec<Torrent>,
+}
+
+#[cfg(feature = "mock")]
+impl ArtistGroup {
+    /// Create a mock [`ArtistGroup`] for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        Self {
+            group_id: 123,
+            group_name: "Test Album".to_owned(),
+            group_year: 2020,
+            group_record_label: "Test Label".to_owned(),
+            group_catalogue_number: "TEST-001".to_owned(),
+            tags: vec!["rock".to_owned()],
+            release_type: ReleaseTypeId(1),
+            group_vanity_house: false,
+            has_bookmarked: false,
+            wiki_image: None,
+            torrents: vec![Torrent::mock()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod decode_tests {
+    use super::*;
+
+    #[test]
+    fn group_text_fields_decoded() {
+        let json = r#"{
+            "groupId": 1,
+            "groupName": "Rock &amp; Roll",
+            "groupYear": 2020,
+            "groupRecordLabel": "Acme &amp; Co",
+            "groupCatalogueNumber": "ABC&#039;123",
+            "tags": [],
+            "releaseType": 1,
+            "groupVanityHouse": false,
+            "hasBookmarked": false,
+            "torrent": []
+        }"#;
+        let group: ArtistGroup = json_from_str(json).expect("fixture should deserialize");
+        assert_eq!(group.group_name, "Rock & Roll");
+        assert_eq!(group.group_record_label, "Acme & Co");
+        assert_eq!(group.group_catalogue_number, "ABC'123");
+    }
+}
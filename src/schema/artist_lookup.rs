@@ -0,0 +1,72 @@
+use crate::prelude::*;
+use urlencoding::encode;
+
+/// Identify an artist for the `artist` action.
+///
+/// Convert from a `u32` id or an artist name with [`From`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ArtistLookup {
+    /// Artist ID
+    Id(u32),
+    /// Artist name
+    ///
+    /// Must exactly match the name on the indexer.
+    Name(String),
+}
+
+impl ArtistLookup {
+    /// Encode the lookup as a query string suitable for `GazelleClient::get`.
+    ///
+    /// - The leading `action=artist` is included
+    #[must_use]
+    pub fn to_query(&self) -> String {
+        match self {
+            Self::Id(id) => format!("action=artist&id={id}"),
+            Self::Name(name) => format!("action=artist&artistname={}", encode(name)),
+        }
+    }
+}
+
+impl From<u32> for ArtistLookup {
+    fn from(id: u32) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&str> for ArtistLookup {
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_owned())
+    }
+}
+
+impl From<String> for ArtistLookup {
+    fn from(name: String) -> Self {
+        Self::Name(name)
+    }
+}
+
+impl Display for ArtistLookup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Id(id) => write!(f, "{id}"),
+            Self::Name(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn artist_lookup_to_query_id() {
+        let output = ArtistLookup::from(1460).to_query();
+        assert_eq!(output, "action=artist&id=1460");
+    }
+
+    #[test]
+    fn artist_lookup_to_query_name() {
+        let output = ArtistLookup::from("Simon & Garfunkel").to_query();
+        assert_eq!(output, "action=artist&artistname=Simon%20%26%20Garfunkel");
+    }
+}
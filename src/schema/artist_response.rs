@@ -0,0 +1,240 @@
+use crate::prelude::*;
+
+/// Response for the `artist` action
+///
+/// <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#artist>
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtistResponse {
+    /// Artist ID
+    pub id: u32,
+    /// Artist name
+    #[serde(deserialize_with = "decode_entities")]
+    pub name: String,
+    /// Are upload notifications enabled for this artist?
+    pub notifications_enabled: bool,
+    /// Is this artist bookmarked by the authenticated user?
+    pub has_bookmarked: bool,
+    /// Artist image URL
+    pub image: String,
+    /// Artist biography
+    ///
+    /// - OPS: HTML
+    /// - RED: BB code
+    #[serde(deserialize_with = "decode_entities")]
+    pub body: String,
+    /// Is this a Vanity House artist?
+    pub vanity_house: bool,
+    /// Tags applied to the artist's groups
+    pub tags: Vec<ArtistTag>,
+    /// Similar artists
+    pub similar_artists: Vec<SimilarArtist>,
+    /// Statistics
+    pub statistics: ArtistStatistics,
+    /// Torrent groups credited to the artist
+    ///
+    /// Referred to as `torrentgroup` in the API response.
+    #[serde(rename = "torrentgroup")]
+    pub torrent_groups: Vec<ArtistGroup>,
+}
+
+/// A tag and the number of the artist's groups it is applied to
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct ArtistTag {
+    /// Tag name
+    pub name: String,
+    /// Number of groups with the tag
+    pub count: u32,
+}
+
+/// An artist similar to the one in an [`ArtistResponse`]
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarArtist {
+    /// Artist ID
+    pub artist_id: u32,
+    /// Artist name
+    #[serde(deserialize_with = "decode_entities")]
+    pub name: String,
+    /// Similarity score
+    pub score: i32,
+    /// ID of the similarity relationship
+    pub similar_id: u32,
+}
+
+/// Artist statistics
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtistStatistics {
+    /// Number of groups
+    pub num_groups: u32,
+    /// Number of torrents
+    pub num_torrents: u32,
+    /// Number of seeders
+    pub num_seeders: u32,
+    /// Number of leechers
+    pub num_leechers: u32,
+    /// Number of snatches
+    pub num_snatches: u32,
+}
+
+#[cfg(feature = "mock")]
+impl ArtistResponse {
+    /// Create a mock [`ArtistResponse`] for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        Self {
+            id: 1,
+            name: "Test Artist".to_owned(),
+            notifications_enabled: false,
+            has_bookmarked: false,
+            image: "https://example.com/artist.jpg".to_owned(),
+            body: "Test biography".to_owned(),
+            vanity_house: false,
+            tags: vec![ArtistTag {
+                name: "rock".to_owned(),
+                count: 1,
+            }],
+            similar_artists: Vec::new(),
+            statistics: ArtistStatistics {
+                num_groups: 1,
+                num_torrents: 1,
+                num_seeders: 50,
+                num_leechers: 2,
+                num_snatches: 100,
+            },
+            torrent_groups: vec![ArtistGroup::mock()],
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::indexing_slicing,
+    reason = "test assertions on known fixture data"
+)]
+mod tests {
+    use super::*;
+
+    const OPS_RESPONSE: &str = include_str!("../tests/fixtures/artist_response_ops.json");
+    const RED_RESPONSE: &str = include_str!("../tests/fixtures/artist_response_red.json");
+
+    #[test]
+    fn deserialize_ops_artist_response() {
+        // Arrange & Act
+        let response: ArtistResponse = json_from_str(OPS_RESPONSE).expect("should deserialize");
+
+        // Assert - Core fields
+        assert_eq!(response.id, 1460);
+        assert_eq!(response.name, "Mock Artist");
+        assert_eq!(response.tags.len(), 2);
+        assert_eq!(response.statistics.num_groups, 2);
+        assert_eq!(response.statistics.num_snatches, 321);
+
+        // Assert - Similar artist names are decoded
+        assert_eq!(response.similar_artists.len(), 1);
+        assert_eq!(response.similar_artists[0].name, "Similar & Co");
+
+        // Assert - OPS has wikiImage on groups
+        assert_eq!(response.torrent_groups.len(), 2);
+        assert!(response.torrent_groups[0].wiki_image.is_some());
+        assert_eq!(
+            response.torrent_groups[1].group_name,
+            "Mock Single & Remixes"
+        );
+        assert_eq!(response.torrent_groups[1].release_type, ReleaseTypeId(9));
+    }
+
+    #[test]
+    fn deserialize_red_artist_response() {
+        // Arrange & Act
+        let response: ArtistResponse = json_from_str(RED_RESPONSE).expect("should deserialize");
+
+        // Assert - Core fields
+        assert_eq!(response.id, 2470);
+        assert!(response.has_bookmarked);
+        assert!(response.similar_artists.is_empty());
+
+        // Assert - RED lacks wikiImage on groups
+        assert_eq!(response.torrent_groups.len(), 1);
+        let group = &response.torrent_groups[0];
+        assert!(group.wiki_image.is_none());
+        assert_eq!(group.group_catalogue_number, "MR-100");
+        assert_eq!(group.tags, vec!["rock".to_owned(), "punk".to_owned()]);
+    }
+
+    #[test]
+    fn deserialize_ops_artist_torrents() {
+        // Arrange & Act
+        let response: ArtistResponse = json_from_str(OPS_RESPONSE).expect("should deserialize");
+
+        // Assert - Torrents reuse the Torrent schema
+        let torrents = &response.torrent_groups[0].torrents;
+        assert_eq!(torrents.len(), 2);
+        assert_eq!(torrents[0].id, 3_000_001);
+        assert_eq!(torrents[0].media, Media::CD);
+        assert_eq!(torrents[0].encoding, Quality::Lossless);
+        assert_eq!(
+            torrents[0].remaster_catalogue_number.as_deref(),
+            Some("MOCK-001")
+        );
+        assert_eq!(torrents[1].encoding, Quality::V0);
+
+        // Assert - OPS lacks remastered
+        assert!(torrents[0].remastered.is_none());
+
+        // Assert - Fields absent from artist responses are None
+        assert!(torrents[0].file_list.is_none());
+        assert!(torrents[0].file_path.is_none());
+        assert!(torrents[0].user_id.is_none());
+        assert!(torrents[0].reported.is_none());
+    }
+
+    #[test]
+    fn deserialize_red_artist_torrents() {
+        // Arrange & Act
+        let response: ArtistResponse = json_from_str(RED_RESPONSE).expect("should deserialize");
+
+        // Assert - RED has remastered but lacks remasterCatalogueNumber
+        let torrents = &response.torrent_groups[0].torrents;
+        assert_eq!(torrents.len(), 2);
+        assert_eq!(torrents[0].media, Media::Vinyl);
+        assert_eq!(torrents[0].encoding, Quality::Lossless24);
+        assert_eq!(torrents[0].remastered, Some(true));
+        assert_eq!(torrents[0].remaster_title, "Original Pressing");
+        assert!(torrents[0].remaster_catalogue_number.is_none());
+        assert_eq!(torrents[1].remastered, Some(false));
+        assert_eq!(torrents[1].log_score, 100);
+    }
+}
+
+#[cfg(test)]
+mod decode_tests {
+    use super::*;
+
+    #[test]
+    fn artist_text_fields_decoded() {
+        let json = r#"{
+            "id": 1,
+            "name": "Simon &amp; Garfunkel",
+            "notificationsEnabled": false,
+            "hasBookmarked": false,
+            "image": "",
+            "body": "Folk &amp; rock",
+            "vanityHouse": false,
+            "tags": [],
+            "similarArtists": [],
+            "statistics": {
+                "numGroups": 0,
+                "numTorrents": 0,
+                "numSeeders": 0,
+                "numLeechers": 0,
+                "numSnatches": 0
+            },
+            "torrentgroup": []
+        }"#;
+        let response: ArtistResponse = json_from_str(json).expect("fixture should deserialize");
+        assert_eq!(response.name, "Simon & Garfunkel");
+        assert_eq!(response.body, "Folk & rock");
+    }
+}
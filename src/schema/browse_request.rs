@@ -6,7 +6,7 @@ use urlencoding::encode;
 /// - All fields are optional; unset fields are omitted from the query string and the server uses its own defaults
 ///
 /// <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#torrents-browse>
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct BrowseRequest {
     /// Format.
     pub format: Option<Format>,
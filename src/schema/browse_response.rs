@@ -145,6 +145,9 @@ mod tests {
         assert_eq!(torrent.format, Format::FLAC);
         assert_eq!(torrent.encoding, Quality::Lossless);
         assert_eq!(torrent.remastered, Some(true));
-        assert_eq!(torrent.remaster_catalogue_number, "MOCK-100");
+        assert_eq!(
+            torrent.remaster_catalogue_number.as_deref(),
+            Some("MOCK-100")
+        );
     }
 }
@@ -99,7 +99,7 @@ impl BrowseTorrent {
             remaster_year: self.remaster_year,
             remaster_title: self.remaster_title.clone(),
             remaster_record_label: self.remaster_record_label.clone().unwrap_or_default(),
-            remaster_catalogue_number: self.remaster_catalogue_number.clone(),
+            remaster_catalogue_number: Some(self.remaster_catalogue_number.clone()),
             scene: self.scene,
             has_log: self.has_log,
             has_cue: self.has_cue,
@@ -0,0 +1,79 @@
+use crate::prelude::*;
+
+/// Response for the `requestfill` action
+#[derive(Clone, Debug, Deserialize)]
+pub struct FillRequestResponse {
+    /// ID of the filled request
+    ///
+    /// Uses serde alias to handle both OPS (`requestid`) and RED (`requestId`) formats.
+    #[serde(rename = "requestId", alias = "requestid")]
+    pub request_id: u32,
+    /// ID of the torrent that filled the request
+    ///
+    /// Uses serde alias to handle both OPS (`torrentid`) and RED (`torrentId`) formats.
+    #[serde(rename = "torrentId", alias = "torrentid")]
+    pub torrent_id: u32,
+    /// ID of the user credited with the fill
+    ///
+    /// Uses serde alias to handle both OPS (`fillerid`) and RED (`fillerId`) formats.
+    #[serde(rename = "fillerId", alias = "fillerid")]
+    pub filler_id: u32,
+    /// Name of the user credited with the fill
+    ///
+    /// Uses serde alias to handle both OPS (`fillername`) and RED (`fillerName`) formats.
+    #[serde(
+        rename = "fillerName",
+        alias = "fillername",
+        deserialize_with = "decode_entities"
+    )]
+    pub filler_name: String,
+    /// Bounty awarded in bytes
+    pub bounty: u64,
+}
+
+#[cfg(feature = "mock")]
+impl FillRequestResponse {
+    /// Create a mock `FillRequestResponse` for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        Self {
+            request_id: 1,
+            torrent_id: 456,
+            filler_id: 1,
+            filler_name: "testuser".to_owned(),
+            bounty: 104_857_600,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPS_RESPONSE: &str = include_str!("../tests/fixtures/fill_request_response_ops.json");
+    const RED_RESPONSE: &str = include_str!("../tests/fixtures/fill_request_response_red.json");
+
+    #[test]
+    fn deserialize_ops_format() {
+        // OPS uses lowercase field names: requestid, torrentid
+        let response: FillRequestResponse =
+            json_from_str(OPS_RESPONSE).expect("Failed to deserialize OPS format");
+        assert_eq!(response.request_id, 40_001);
+        assert_eq!(response.torrent_id, 3_000_001);
+        assert_eq!(response.filler_id, 12_345);
+        assert_eq!(response.filler_name, "TestUser");
+        assert_eq!(response.bounty, 209_715_200);
+    }
+
+    #[test]
+    fn deserialize_red_format() {
+        // RED uses camelCase field names: requestId, torrentId
+        let response: FillRequestResponse =
+            json_from_str(RED_RESPONSE).expect("Failed to deserialize RED format");
+        assert_eq!(response.request_id, 80_001);
+        assert_eq!(response.torrent_id, 6_000_001);
+        assert_eq!(response.filler_id, 54_321);
+        assert_eq!(response.filler_name, "Test & User");
+        assert_eq!(response.bounty, 524_288_000);
+    }
+}
@@ -153,10 +153,10 @@ mod tests {
         let response: GroupResponse = json_from_str(RED_RESPONSE).expect("should deserialize");
 
         // Assert - Different uploaders for each torrent
-        assert_eq!(response.torrents[0].user_id, 2001);
-        assert_eq!(response.torrents[0].username, "user_a");
-        assert_eq!(response.torrents[1].user_id, 2002);
-        assert_eq!(response.torrents[1].username, "user_b");
+        assert_eq!(response.torrents[0].user_id, Some(2001));
+        assert_eq!(response.torrents[0].username.as_deref(), Some("user_a"));
+        assert_eq!(response.torrents[1].user_id, Some(2002));
+        assert_eq!(response.torrents[1].username.as_deref(), Some("user_b"));
     }
 
     #[test]
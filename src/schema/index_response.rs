@@ -0,0 +1,200 @@
+use crate::prelude::*;
+
+/// Response for the `index` action
+///
+/// Describes the account that owns the API key.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexResponse {
+    /// Username
+    #[serde(deserialize_with = "decode_entities")]
+    pub username: String,
+    /// User ID
+    pub id: u32,
+    /// Authentication key used by site forms
+    pub authkey: String,
+    /// Passkey used in announce URLs
+    pub passkey: String,
+    /// API version
+    ///
+    /// *RED only*
+    #[serde(rename = "api_version")]
+    pub api_version: Option<String>,
+    /// Unread notification counts
+    pub notifications: IndexNotifications,
+    /// Upload and download statistics
+    pub userstats: IndexUserStats,
+}
+
+/// Unread notification counts of the authenticated user
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexNotifications {
+    /// Number of unread private messages
+    pub messages: u32,
+    /// Number of unread torrent notifications
+    pub notifications: u32,
+    /// Is there an unread announcement?
+    pub new_announcement: bool,
+    /// Is there an unread blog post?
+    pub new_blog: bool,
+    /// Are there unread forum subscriptions?
+    pub new_subscriptions: bool,
+}
+
+/// Statistics of the authenticated user
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexUserStats {
+    /// Bytes uploaded
+    pub uploaded: u64,
+    /// Bytes downloaded
+    pub downloaded: u64,
+    /// Ratio
+    pub ratio: f32,
+    /// Ratio required to maintain member level
+    #[serde(rename = "requiredratio")]
+    pub required_ratio: f32,
+    /// Bonus points
+    pub bonus_points: Option<u64>,
+    /// Bonus points earned per hour
+    ///
+    /// *RED only*
+    pub bonus_points_per_hour: Option<f32>,
+    /// Class
+    pub class: String,
+}
+
+#[cfg(feature = "mock")]
+impl IndexResponse {
+    /// Create a mock `IndexResponse` for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        Self {
+            username: "testuser".to_owned(),
+            id: 1,
+            authkey: "testauthkey".to_owned(),
+            passkey: "testpasskey".to_owned(),
+            api_version: None,
+            notifications: IndexNotifications::mock(),
+            userstats: IndexUserStats::mock(),
+        }
+    }
+}
+
+#[cfg(feature = "mock")]
+impl IndexNotifications {
+    /// Create a mock `IndexNotifications` for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        Self {
+            messages: 0,
+            notifications: 0,
+            new_announcement: false,
+            new_blog: false,
+            new_subscriptions: false,
+        }
+    }
+}
+
+#[cfg(feature = "mock")]
+impl IndexUserStats {
+    /// Create a mock `IndexUserStats` for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        Self {
+            uploaded: 1_000_000_000,
+            downloaded: 500_000_000,
+            ratio: 2.0,
+            required_ratio: 0.5,
+            bonus_points: Some(1000),
+            bonus_points_per_hour: None,
+            class: "Member".to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::float_cmp,
+    reason = "exact float equality is intentional in fixture tests"
+)]
+mod tests {
+    use super::*;
+
+    const OPS_RESPONSE: &str = include_str!("../tests/fixtures/index_response_ops.json");
+    const RED_RESPONSE: &str = include_str!("../tests/fixtures/index_response_red.json");
+
+    #[test]
+    fn deserialize_ops_index_response() {
+        // Arrange & Act
+        let response: IndexResponse = json_from_str(OPS_RESPONSE).expect("should deserialize");
+
+        // Assert - OPS lacks RED-specific fields
+        assert!(response.api_version.is_none());
+        assert!(response.userstats.bonus_points_per_hour.is_none());
+
+        // Assert - Core fields
+        assert_eq!(response.username, "TestUser");
+        assert_eq!(response.id, 12345);
+        assert_eq!(response.authkey.len(), 32);
+        assert_eq!(response.passkey.len(), 32);
+        assert_eq!(response.notifications.messages, 2);
+        assert!(response.notifications.new_blog);
+        assert_eq!(response.userstats.uploaded, 1_207_152_087_233);
+        assert_eq!(response.userstats.ratio, 3.23);
+        assert_eq!(response.userstats.required_ratio, 0.6);
+        assert_eq!(response.userstats.bonus_points, Some(840_671));
+        assert_eq!(response.userstats.class, "Torrent Master");
+    }
+
+    #[test]
+    fn deserialize_red_index_response() {
+        // Arrange & Act
+        let response: IndexResponse = json_from_str(RED_RESPONSE).expect("should deserialize");
+
+        // Assert - RED-specific fields are present
+        assert_eq!(response.api_version.as_deref(), Some("redacted-v2.0"));
+        assert_eq!(response.userstats.bonus_points_per_hour, Some(42.5));
+
+        // Assert - Core fields
+        assert_eq!(response.username, "TestUser");
+        assert_eq!(response.id, 54321);
+        assert_eq!(response.notifications.notifications, 9000);
+        assert!(response.notifications.new_announcement);
+        assert!(response.notifications.new_subscriptions);
+        assert_eq!(response.userstats.downloaded, 878_944_102_475);
+        assert_eq!(response.userstats.class, "Elite");
+    }
+}
+
+#[cfg(test)]
+mod decode_tests {
+    use super::*;
+
+    #[test]
+    fn username_decoded() {
+        let json = r#"{
+            "username": "DJ &amp; MC",
+            "id": 1,
+            "authkey": "a",
+            "passkey": "p",
+            "notifications": {
+                "messages": 0,
+                "notifications": 0,
+                "newAnnouncement": false,
+                "newBlog": false,
+                "newSubscriptions": false
+            },
+            "userstats": {
+                "uploaded": 0,
+                "downloaded": 0,
+                "ratio": 0,
+                "requiredratio": 0,
+                "class": "Member"
+            }
+        }"#;
+        let response: IndexResponse = json_from_str(json).expect("fixture should deserialize");
+        assert_eq!(response.username, "DJ & MC");
+    }
+}
@@ -1,4 +1,7 @@
 pub use api_response::*;
+pub use artist_group::*;
+pub use artist_lookup::*;
+pub use artist_response::*;
 pub use browse_group::*;
 pub use browse_request::*;
 pub use browse_response::*;
@@ -7,22 +10,34 @@ pub use category::*;
 pub use credit::*;
 pub use credits::*;
 pub use file_item::*;
+pub use fill_request_response::*;
 pub use format::*;
 pub use group::*;
 pub use group_response::*;
+pub use index_response::*;
 pub use media::*;
 pub use order_by::*;
 pub use order_way::*;
 pub use quality::*;
 pub use release_type::*;
 pub use release_type_id::*;
+pub use request_detail::*;
+pub use request_search::*;
+pub use request_search_response::*;
+pub use request_summary::*;
 pub use torrent::*;
 pub use torrent_response::*;
 pub use upload_form::*;
 pub use upload_response::*;
 pub use user::*;
+pub use user_torrent::*;
+pub use user_torrent_kind::*;
+pub use user_torrents_response::*;
 
 mod api_response;
+mod artist_group;
+mod artist_lookup;
+mod artist_response;
 mod browse_group;
 mod browse_request;
 mod browse_response;
@@ -31,17 +46,26 @@ mod category;
 mod credit;
 mod credits;
 mod file_item;
+mod fill_request_response;
 mod format;
 mod group;
 mod group_response;
+mod index_response;
 mod media;
 mod order_by;
 mod order_way;
 mod quality;
 mod release_type;
 mod release_type_id;
+mod request_detail;
+mod request_search;
+mod request_search_response;
+mod request_summary;
 mod torrent;
 mod torrent_response;
 mod upload_form;
 mod upload_response;
 mod user;
+mod user_torrent;
+mod user_torrent_kind;
+mod user_torrents_response;
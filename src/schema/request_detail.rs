@@ -0,0 +1,252 @@
+use crate::prelude::*;
+
+/// Response for the `request` action
+///
+/// <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#request>
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[expect(clippy::struct_excessive_bools)]
+pub struct RequestDetail {
+    /// Request ID
+    pub request_id: u32,
+    /// ID of the user who created the request
+    pub requestor_id: u32,
+    /// Name of the user who created the request
+    #[serde(deserialize_with = "decode_entities")]
+    pub requestor_name: String,
+    /// Is this request bookmarked by the authenticated user?
+    pub is_bookmarked: bool,
+    /// Fraction of each vote deducted as tax
+    pub request_tax: f32,
+    /// Time the request was created
+    pub time_added: String,
+    /// Can the authenticated user edit the request?
+    pub can_edit: bool,
+    /// Can the authenticated user vote on the request?
+    pub can_vote: bool,
+    /// Minimum vote in bytes
+    pub minimum_vote: u64,
+    /// Number of votes
+    pub vote_count: u32,
+    /// Time of the most recent vote
+    #[serde(default)]
+    pub last_vote: Option<String>,
+    /// Users who contributed the largest bounties
+    pub top_contributors: Vec<RequestContributor>,
+    /// Total bounty in bytes
+    pub total_bounty: u64,
+    /// Category
+    pub category_id: Category,
+    /// Category name
+    pub category_name: String,
+    /// Requested release name
+    #[serde(deserialize_with = "decode_entities")]
+    pub title: String,
+    /// Requested release year
+    pub year: u16,
+    /// Cover image URL
+    pub image: String,
+    /// Description formatted as BB code
+    #[serde(default, deserialize_with = "decode_entities_opt")]
+    pub bb_description: Option<String>,
+    /// Description formatted as HTML
+    #[serde(deserialize_with = "decode_entities")]
+    pub description: String,
+    /// Requested credits.
+    ///
+    /// Artists, composers, etc.
+    #[serde(default)]
+    pub music_info: Option<Credits>,
+    /// Catalogue number
+    #[serde(deserialize_with = "decode_entities")]
+    pub catalogue_number: String,
+    /// Record label
+    #[serde(default, deserialize_with = "decode_entities_opt")]
+    pub record_label: Option<String>,
+    /// Release type
+    pub release_type: ReleaseTypeId,
+    /// Release type name
+    pub release_name: String,
+    /// Acceptable qualities
+    ///
+    /// - Empty when any quality is accepted
+    #[serde(deserialize_with = "pipe_list")]
+    pub bitrate_list: Vec<Quality>,
+    /// Acceptable formats
+    ///
+    /// - Empty when any format is accepted
+    #[serde(deserialize_with = "pipe_list")]
+    pub format_list: Vec<Format>,
+    /// Acceptable media
+    ///
+    /// - Empty when any media is accepted
+    #[serde(deserialize_with = "pipe_list")]
+    pub media_list: Vec<Media>,
+    /// Log and cue requirements
+    pub log_cue: String,
+    /// Has the request been filled?
+    pub is_filled: bool,
+    /// ID of the user who filled the request
+    ///
+    /// - `0` when unfilled
+    pub filler_id: u32,
+    /// Name of the user who filled the request
+    ///
+    /// - Empty when unfilled
+    #[serde(deserialize_with = "decode_entities")]
+    pub filler_name: String,
+    /// ID of the torrent that filled the request
+    ///
+    /// - `0` when unfilled
+    pub torrent_id: u32,
+    /// Time the request was filled
+    #[serde(default)]
+    pub time_filled: Option<String>,
+    /// Tags
+    pub tags: Vec<String>,
+}
+
+/// A user who added bounty to a [`RequestDetail`]
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestContributor {
+    /// User ID
+    pub user_id: u32,
+    /// Username
+    #[serde(deserialize_with = "decode_entities")]
+    pub user_name: String,
+    /// Bounty contributed in bytes
+    pub bounty: u64,
+}
+
+#[cfg(feature = "mock")]
+impl RequestDetail {
+    /// Create a mock [`RequestDetail`] for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        Self {
+            request_id: 1,
+            requestor_id: 1,
+            requestor_name: "testuser".to_owned(),
+            is_bookmarked: false,
+            request_tax: 0.0,
+            time_added: "2020-01-01 00:00:00".to_owned(),
+            can_edit: true,
+            can_vote: true,
+            minimum_vote: 20_971_520,
+            vote_count: 1,
+            last_vote: Some("2020-01-02 00:00:00".to_owned()),
+            top_contributors: vec![RequestContributor {
+                user_id: 1,
+                user_name: "testuser".to_owned(),
+                bounty: 104_857_600,
+            }],
+            total_bounty: 104_857_600,
+            category_id: Category::Music,
+            category_name: "Music".to_owned(),
+            title: "Test Album".to_owned(),
+            year: 2020,
+            image: String::new(),
+            bb_description: None,
+            description: "Test description".to_owned(),
+            music_info: None,
+            catalogue_number: String::new(),
+            record_label: None,
+            release_type: ReleaseTypeId(1),
+            release_name: "Album".to_owned(),
+            bitrate_list: vec![Quality::Lossless],
+            format_list: vec![Format::FLAC],
+            media_list: vec![Media::CD, Media::WEB],
+            log_cue: String::new(),
+            is_filled: false,
+            filler_id: 0,
+            filler_name: String::new(),
+            torrent_id: 0,
+            time_filled: None,
+            tags: vec!["rock".to_owned()],
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::float_cmp,
+    clippy::indexing_slicing,
+    reason = "test assertions on known fixture data"
+)]
+mod tests {
+    use super::*;
+
+    const OPS_RESPONSE: &str = include_str!("../tests/fixtures/request_detail_ops.json");
+    const RED_RESPONSE: &str = include_str!("../tests/fixtures/request_detail_red.json");
+
+    #[test]
+    fn deserialize_ops_request_detail() {
+        // Arrange & Act
+        let response: RequestDetail = json_from_str(OPS_RESPONSE).expect("should deserialize");
+
+        // Assert - Core fields
+        assert_eq!(response.request_id, 40_001);
+        assert_eq!(response.title, "Mock Album");
+        assert_eq!(response.release_name, "Album");
+        assert_eq!(response.vote_count, 2);
+        assert_eq!(response.total_bounty, 209_715_200);
+        assert_eq!(response.request_tax, 0.0);
+        assert_eq!(response.top_contributors.len(), 2);
+        assert_eq!(response.top_contributors[0].user_name, "Requester");
+
+        // Assert - Lists are arrays in the detail response
+        assert_eq!(response.format_list, vec![Format::FLAC]);
+        assert_eq!(
+            response.bitrate_list,
+            vec![Quality::Lossless, Quality::Lossless24]
+        );
+        assert_eq!(response.media_list, vec![Media::CD, Media::Vinyl]);
+
+        // Assert - OPS lacks bbDescription and recordLabel
+        assert!(response.bb_description.is_none());
+        assert!(response.record_label.is_none());
+
+        // Assert - Credits
+        let credits = response.music_info.expect("should have music info");
+        assert_eq!(credits.artists[0].name, "Mock Artist");
+        assert!(!response.is_filled);
+    }
+
+    #[test]
+    fn deserialize_red_request_detail() {
+        // Arrange & Act
+        let response: RequestDetail = json_from_str(RED_RESPONSE).expect("should deserialize");
+
+        // Assert - RED-specific fields are present
+        assert_eq!(
+            response.bb_description.as_deref(),
+            Some("[b]Any[/b] pressing & edition")
+        );
+        assert_eq!(response.record_label.as_deref(), Some("Mock Records"));
+        assert_eq!(response.request_tax, 0.1);
+
+        // Assert - Filled status
+        assert!(response.is_filled);
+        assert_eq!(response.filler_id, 777);
+        assert_eq!(response.torrent_id, 6_000_001);
+        assert_eq!(response.time_filled.as_deref(), Some("2024-03-02 10:00:00"));
+
+        // Assert - Any is represented as an empty list
+        assert!(response.media_list.is_empty());
+        assert_eq!(response.format_list, vec![Format::FLAC, Format::MP3]);
+    }
+}
+
+#[cfg(test)]
+mod decode_tests {
+    use super::*;
+
+    #[test]
+    fn contributor_name_decoded() {
+        let json = r#"{"userId":1,"userName":"DJ &amp; MC","bounty":0}"#;
+        let contributor: RequestContributor =
+            json_from_str(json).expect("fixture should deserialize");
+        assert_eq!(contributor.user_name, "DJ & MC");
+    }
+}
@@ -0,0 +1,113 @@
+use crate::prelude::*;
+use urlencoding::encode;
+
+/// Parameters for the Gazelle requests action.
+///
+/// - All fields are optional; unset fields are omitted from the query string and the server uses its own defaults
+///
+/// <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#request-search>
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RequestSearch {
+    /// General search string.
+    ///
+    /// Matches across artist name, title, and year.
+    pub search: Option<String>,
+    /// Page number (1-indexed).
+    pub page: Option<u32>,
+    /// Tag.
+    pub tags: Option<Vec<String>>,
+    /// Tag matching operator.
+    pub tags_operator: Option<TagsOperator>,
+    /// Include filled requests.
+    ///
+    /// The server excludes filled requests by default.
+    pub show_filled: Option<bool>,
+    /// Category.
+    ///
+    /// The API supports multiple categories but only single
+    /// category filtering is exposed here.
+    pub category: Option<Category>,
+}
+
+impl RequestSearch {
+    /// Encode the search as a query string suitable for `GazelleClient::get`.
+    ///
+    /// - The leading `action=requests` is included
+    #[must_use]
+    pub fn to_query(&self) -> String {
+        let mut parts: Vec<(String, String)> = vec![("action".to_owned(), "requests".to_owned())];
+        if let Some(search) = &self.search {
+            parts.push(("search".to_owned(), search.clone()));
+        }
+        if let Some(page) = self.page {
+            parts.push(("page".to_owned(), page.to_string()));
+        }
+        if let Some(tags) = &self.tags {
+            parts.push(("tags".to_owned(), tags.join(",")));
+        }
+        if let Some(tags_operator) = &self.tags_operator {
+            parts.push(("tags_type".to_owned(), tags_operator.as_query().to_owned()));
+        }
+        if let Some(show_filled) = self.show_filled {
+            parts.push((
+                "show_filled".to_owned(),
+                if show_filled { "1" } else { "0" }.to_owned(),
+            ));
+        }
+        if let Some(category) = &self.category {
+            parts.push((
+                format!("filter_cat[{}]", category.to_group()),
+                "1".to_owned(),
+            ));
+        }
+        parts
+            .iter()
+            .map(|(k, v)| format!("{k}={}", encode(v)))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_search_to_query_empty() {
+        let search = RequestSearch::default();
+        let output = search.to_query();
+        assert_eq!(output, "action=requests");
+    }
+
+    #[test]
+    fn request_search_to_query_full() {
+        // Arrange
+        let search = RequestSearch {
+            search: Some("test album".to_owned()),
+            page: Some(2),
+            tags: Some(vec!["jazz".to_owned(), "piano".to_owned()]),
+            tags_operator: Some(TagsOperator::And),
+            show_filled: Some(true),
+            category: Some(Category::Music),
+        };
+
+        // Act
+        let output = search.to_query();
+
+        // Assert
+        assert_eq!(
+            output,
+            "action=requests&search=test%20album&page=2&tags=jazz%2Cpiano&tags_type=1&show_filled=1&filter_cat[1]=1"
+        );
+    }
+
+    #[test]
+    fn request_search_to_query_hide_filled() {
+        let search = RequestSearch {
+            show_filled: Some(false),
+            ..RequestSearch::default()
+        };
+        let output = search.to_query();
+        assert_eq!(output, "action=requests&show_filled=0");
+    }
+}
@@ -0,0 +1,94 @@
+use crate::prelude::*;
+
+/// Response from the Gazelle requests action.
+///
+/// <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#request-search>
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestSearchResponse {
+    /// Current page number (1-indexed).
+    pub current_page: u32,
+    /// Total number of pages.
+    pub pages: u32,
+    /// One entry per matching request.
+    pub results: Vec<RequestSummary>,
+}
+
+#[cfg(feature = "mock")]
+impl RequestSearchResponse {
+    /// Create a mock [`RequestSearchResponse`] for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        Self {
+            current_page: 1,
+            pages: 1,
+            results: vec![RequestSummary::mock()],
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::indexing_slicing,
+    reason = "test assertions on known fixture data"
+)]
+mod tests {
+    use super::*;
+
+    const OPS_RESPONSE: &str = include_str!("../tests/fixtures/request_search_response_ops.json");
+    const RED_RESPONSE: &str = include_str!("../tests/fixtures/request_search_response_red.json");
+
+    #[test]
+    fn deserialize_ops_request_search_response() {
+        // Arrange & Act
+        let response: RequestSearchResponse =
+            json_from_str(OPS_RESPONSE).expect("should deserialize");
+
+        // Assert - Pagination
+        assert_eq!(response.current_page, 1);
+        assert_eq!(response.pages, 12);
+        assert_eq!(response.results.len(), 2);
+
+        // Assert - Unfilled request with pipe separated lists
+        let request = &response.results[0];
+        assert_eq!(request.request_id, 40_001);
+        assert_eq!(request.bounty, 209_715_200);
+        assert_eq!(request.vote_count, 2);
+        assert_eq!(request.artists[0][0].name, "Mock Artist");
+        assert_eq!(request.format_list, vec![Format::FLAC]);
+        assert_eq!(
+            request.bitrate_list,
+            vec![Quality::Lossless, Quality::Lossless24]
+        );
+        assert_eq!(request.media_list, vec![Media::CD, Media::Vinyl]);
+        assert!(!request.is_filled);
+        assert_eq!(request.torrent_id, 0);
+
+        // Assert - Any is represented as an empty list
+        let request = &response.results[1];
+        assert!(request.format_list.is_empty());
+        assert!(request.bitrate_list.is_empty());
+        assert!(request.media_list.is_empty());
+    }
+
+    #[test]
+    fn deserialize_red_request_search_response() {
+        // Arrange & Act
+        let response: RequestSearchResponse =
+            json_from_str(RED_RESPONSE).expect("should deserialize");
+
+        // Assert - Filled request
+        assert_eq!(response.results.len(), 1);
+        let request = &response.results[0];
+        assert_eq!(request.request_id, 80_001);
+        assert_eq!(request.title, "Mock Album & Friends");
+        assert_eq!(request.release_type, ReleaseTypeId(1));
+        assert_eq!(request.format_list, vec![Format::FLAC, Format::MP3]);
+        assert_eq!(request.bitrate_list, vec![Quality::Lossless, Quality::V0]);
+        assert_eq!(request.media_list, vec![Media::WEB]);
+        assert!(request.is_filled);
+        assert_eq!(request.filler_name, "Filler");
+        assert_eq!(request.torrent_id, 6_000_001);
+        assert_eq!(request.time_filled.as_deref(), Some("2024-03-02 10:00:00"));
+    }
+}
@@ -0,0 +1,164 @@
+use crate::prelude::*;
+
+/// A single request entry in a [`RequestSearchResponse`].
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestSummary {
+    /// Request ID
+    pub request_id: u32,
+    /// ID of the user who created the request
+    pub requestor_id: u32,
+    /// Name of the user who created the request
+    #[serde(deserialize_with = "decode_entities")]
+    pub requestor_name: String,
+    /// Time the request was created
+    pub time_added: String,
+    /// Time of the most recent vote
+    #[serde(default)]
+    pub last_vote: Option<String>,
+    /// Number of votes
+    pub vote_count: u32,
+    /// Total bounty in bytes
+    pub bounty: u64,
+    /// Category
+    pub category_id: Category,
+    /// Category name
+    pub category_name: String,
+    /// Requested artists
+    ///
+    /// Each inner list is one set of credits.
+    pub artists: Vec<Vec<Credit>>,
+    /// Requested release name
+    #[serde(deserialize_with = "decode_entities")]
+    pub title: String,
+    /// Requested release year
+    pub year: u16,
+    /// Cover image URL
+    pub image: String,
+    /// Description formatted as BB code
+    #[serde(deserialize_with = "decode_entities")]
+    pub description: String,
+    /// Record label
+    #[serde(deserialize_with = "decode_entities")]
+    pub record_label: String,
+    /// Catalogue number
+    #[serde(deserialize_with = "decode_entities")]
+    pub catalogue_number: String,
+    /// Release type
+    pub release_type: ReleaseTypeId,
+    /// Acceptable qualities
+    ///
+    /// - Empty when any quality is accepted
+    #[serde(deserialize_with = "pipe_list")]
+    pub bitrate_list: Vec<Quality>,
+    /// Acceptable formats
+    ///
+    /// - Empty when any format is accepted
+    #[serde(deserialize_with = "pipe_list")]
+    pub format_list: Vec<Format>,
+    /// Acceptable media
+    ///
+    /// - Empty when any media is accepted
+    #[serde(deserialize_with = "pipe_list")]
+    pub media_list: Vec<Media>,
+    /// Log and cue requirements
+    pub log_cue: String,
+    /// Has the request been filled?
+    pub is_filled: bool,
+    /// ID of the user who filled the request
+    ///
+    /// - `0` when unfilled
+    pub filler_id: u32,
+    /// Name of the user who filled the request
+    ///
+    /// - Empty when unfilled
+    #[serde(deserialize_with = "decode_entities")]
+    pub filler_name: String,
+    /// ID of the torrent that filled the request
+    ///
+    /// - `0` when unfilled
+    pub torrent_id: u32,
+    /// Time the request was filled
+    #[serde(default)]
+    pub time_filled: Option<String>,
+}
+
+#[cfg(feature = "mock")]
+impl RequestSummary {
+    /// Create a mock [`RequestSummary`] for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        Self {
+            request_id: 1,
+            requestor_id: 1,
+            requestor_name: "testuser".to_owned(),
+            time_added: "2020-01-01 00:00:00".to_owned(),
+            last_vote: Some("2020-01-02 00:00:00".to_owned()),
+            vote_count: 1,
+            bounty: 104_857_600,
+            category_id: Category::Music,
+            category_name: "Music".to_owned(),
+            artists: vec![vec![Credit {
+                id: 1,
+                name: "Test Artist".to_owned(),
+            }]],
+            title: "Test Album".to_owned(),
+            year: 2020,
+            image: String::new(),
+            description: "Test description".to_owned(),
+            record_label: String::new(),
+            catalogue_number: String::new(),
+            release_type: ReleaseTypeId(1),
+            bitrate_list: vec![Quality::Lossless],
+            format_list: vec![Format::FLAC],
+            media_list: vec![Media::CD, Media::WEB],
+            log_cue: String::new(),
+            is_filled: false,
+            filler_id: 0,
+            filler_name: String::new(),
+            torrent_id: 0,
+            time_filled: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod decode_tests {
+    use super::*;
+
+    #[test]
+    fn request_summary_text_fields_decoded() {
+        let json = r#"{
+            "requestId": 1,
+            "requestorId": 2,
+            "requestorName": "DJ &amp; MC",
+            "timeAdded": "2020-01-01 00:00:00",
+            "voteCount": 1,
+            "bounty": 0,
+            "categoryId": 1,
+            "categoryName": "Music",
+            "artists": [],
+            "title": "Rock &amp; Roll",
+            "year": 2020,
+            "image": "",
+            "description": "",
+            "recordLabel": "Acme &amp; Co",
+            "catalogueNumber": "ABC&#039;123",
+            "releaseType": 1,
+            "bitrateList": "Any",
+            "formatList": "Any",
+            "mediaList": "Any",
+            "logCue": "",
+            "isFilled": false,
+            "fillerId": 0,
+            "fillerName": "",
+            "torrentId": 0
+        }"#;
+        let request: RequestSummary = json_from_str(json).expect("fixture should deserialize");
+        assert_eq!(request.requestor_name, "DJ & MC");
+        assert_eq!(request.title, "Rock & Roll");
+        assert_eq!(request.record_label, "Acme & Co");
+        assert_eq!(request.catalogue_number, "ABC'123");
+        assert!(request.bitrate_list.is_empty());
+    }
+}
@@ -5,7 +5,6 @@ use crate::prelude::*;
 /// <https://github.com/OPSnet/Gazelle/blob/master/docs/07-API.md#torrent>
 #[derive(Clone, Debug, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
-#[expect(clippy::struct_excessive_bools)]
 pub struct Torrent {
     /// ID number
     pub id: u32,
@@ -35,8 +34,10 @@ pub struct Torrent {
     #[serde(deserialize_with = "decode_entities")]
     pub remaster_record_label: String,
     /// Edition catalogue number
-    #[serde(deserialize_with = "decode_entities")]
-    pub remaster_catalogue_number: String,
+    ///
+    /// *Absent from RED `artist` responses*
+    #[serde(default, deserialize_with = "decode_entities_opt")]
+    pub remaster_catalogue_number: Option<String>,
     /// Is this a scene release?
     pub scene: bool,
     /// Is there a log?
@@ -84,25 +85,36 @@ pub struct Torrent {
     /// *RED only*
     pub is_freeload: Option<bool>,
     /// Has this been reported?
-    pub reported: bool,
+    ///
+    /// *Absent from `artist` responses*
+    pub reported: Option<bool>,
     /// Time of last logged event
     pub time: String,
     /// Description formatted as BB code
-    #[serde(deserialize_with = "decode_entities")]
-    pub description: String,
+    ///
+    /// *Absent from `artist` responses*
+    #[serde(default, deserialize_with = "decode_entities_opt")]
+    pub description: Option<String>,
     /// Raw `name{{{size}}}|||...` file list.
     ///
     /// - File names are HTML-entity-encoded as returned by Gazelle.
     /// - Use [`parse_file_list`] to get decoded [`FileItem`].
-    pub file_list: String,
+    /// - *Absent from `artist` responses*
+    pub file_list: Option<String>,
     /// The name of the torrent directory
-    #[serde(deserialize_with = "decode_entities")]
-    pub file_path: String,
+    ///
+    /// *Absent from `artist` responses*
+    #[serde(default, deserialize_with = "decode_entities_opt")]
+    pub file_path: Option<String>,
     /// ID of uploader
-    pub user_id: u32,
+    ///
+    /// *Absent from `artist` responses*
+    pub user_id: Option<u32>,
     /// Username of uploader
-    #[serde(deserialize_with = "decode_entities")]
-    pub username: String,
+    ///
+    /// *Absent from `artist` responses*
+    #[serde(default, deserialize_with = "decode_entities_opt")]
+    pub username: Option<String>,
 }
 
 impl Torrent {
@@ -119,7 +131,7 @@ impl Torrent {
     /// Parse the file list into a vec of [`FileItem`] entries, sorted by filename.
     #[must_use]
     pub fn get_files(&self) -> Vec<FileItem> {
-        let mut files = parse_file_list(&self.file_list);
+        let mut files = parse_file_list(self.file_list.as_deref().unwrap_or_default());
         files.sort_by(|a, b| a.name.cmp(&b.name));
         files
     }
@@ -139,7 +151,7 @@ impl Torrent {
             remaster_year: Some(2020),
             remaster_title: String::new(),
             remaster_record_label: "Test Label".to_owned(),
-            remaster_catalogue_number: "TEST-001".to_owned(),
+            remaster_catalogue_number: Some("TEST-001".to_owned()),
             scene: false,
             has_log: true,
             has_cue: true,
@@ -156,13 +168,13 @@ impl Torrent {
             free_torrent: None,
             is_neutralleech: None,
             is_freeload: None,
-            reported: false,
+            reported: Some(false),
             time: "2020-01-01 00:00:00".to_owned(),
-            description: "Test description".to_owned(),
-            file_list: "test.flac{{{100000}}}".to_owned(),
-            file_path: "Test Album (2020) [FLAC]".to_owned(),
-            user_id: 1,
-            username: "uploader".to_owned(),
+            description: Some("Test description".to_owned()),
+            file_list: Some("test.flac{{{100000}}}".to_owned()),
+            file_path: Some("Test Album (2020) [FLAC]".to_owned()),
+            user_id: Some(1),
+            username: Some("uploader".to_owned()),
         }
     }
 }
@@ -176,7 +188,7 @@ mod tests {
         // Arrange
         let file_list = r"file1.flac{{{12345}}}|||file2.flac{{{67890}}}|||file with spaces.flac{{{54321}}}|||another_file.flac{{{98765}}}|||/path/to/file.flac{{{11111}}}|||C:\windows\path\file.flac{{{22222}}}|||Disc 1/01. track with period.flac{{{33333}}}|||Disc 1/02. track-with-dash.flac{{{44444}}}|||track_with_underscores.flac{{{55555}}}|||file_with_numbers_123.flac{{{66666}}}|||special&char#file.flac{{{77777}}}|||final_file.flac{{{88888}}}|||cover.jpg{{{123456}}}|||archive.zip{{{234567}}}|||executable.exe{{{345678}}}|||document.pdf{{{456789}}}|||presentation.pptx{{{567890}}}|||disc-image.iso{{{678901}}}|||compressed.tar.gz{{{789012}}}|||photo.png{{{890123}}}|||audio.mp3{{{901234}}}|||final.zip{{{912345}}}".to_owned();
         let torrent = Torrent {
-            file_list,
+            file_list: Some(file_list),
             ..Torrent::default()
         };
 
@@ -208,9 +220,10 @@ mod tests {
         #[test]
         fn torrent_get_files_decodes_entities() {
             let torrent = Torrent {
-                file_list:
+                file_list: Some(
                     "Artist &amp; Title.flac{{{12345}}}|||cover &#039;art&#039;.jpg{{{500}}}"
                         .to_owned(),
+                ),
                 ..Torrent::mock()
             };
             let output = torrent.get_files();
@@ -229,7 +242,7 @@ mod tests {
         fn torrent_get_files() {
             // Arrange
             let torrent = Torrent {
-                file_list: "01 - Track.flac{{{12345678}}}|||cover.jpg{{{98765}}}".to_owned(),
+                file_list: Some("01 - Track.flac{{{12345678}}}|||cover.jpg{{{98765}}}".to_owned()),
                 ..Torrent::mock()
             };
 
@@ -251,7 +264,7 @@ mod tests {
         #[test]
         fn torrent_get_files_single_item() {
             let torrent = Torrent {
-                file_list: "single.flac{{{100}}}".to_owned(),
+                file_list: Some("single.flac{{{100}}}".to_owned()),
                 ..Torrent::mock()
             };
             let output = torrent.get_files();
@@ -265,7 +278,7 @@ mod tests {
         #[test]
         fn torrent_get_files_empty() {
             let torrent = Torrent {
-                file_list: String::new(),
+                file_list: Some(String::new()),
                 ..Torrent::mock()
             };
             assert!(torrent.get_files().is_empty());
@@ -274,7 +287,7 @@ mod tests {
         #[test]
         fn torrent_get_files_malformed_entries_skipped() {
             let torrent = Torrent {
-                file_list: "goodfile.flac{{{100}}}|||badentry".to_owned(),
+                file_list: Some("goodfile.flac{{{100}}}|||badentry".to_owned()),
                 ..Torrent::mock()
             };
             let output = torrent.get_files();
@@ -329,9 +342,12 @@ mod decode_tests {
         let torrent: Torrent = json_from_str(json).expect("fixture should deserialize");
         assert_eq!(torrent.remaster_title, "Deluxe & Expanded");
         assert_eq!(torrent.remaster_record_label, "Acme & Co");
-        assert_eq!(torrent.remaster_catalogue_number, "ABC'123");
-        assert_eq!(torrent.description, "Notes & info");
-        assert_eq!(torrent.file_path, "Artist & Title");
-        assert_eq!(torrent.username, "DJ & MC");
+        assert_eq!(
+            torrent.remaster_catalogue_number.as_deref(),
+            Some("ABC'123")
+        );
+        assert_eq!(torrent.description.as_deref(), Some("Notes & info"));
+        assert_eq!(torrent.file_path.as_deref(), Some("Artist & Title"));
+        assert_eq!(torrent.username.as_deref(), Some("DJ & MC"));
     }
 }
@@ -151,7 +151,13 @@ mod tests {
         let response: TorrentResponse = json_from_str(OPS_RESPONSE).expect("should deserialize");
 
         // Assert - File list is preserved
-        assert!(response.torrent.file_list.contains("Track.flac"));
+        assert!(
+            response
+                .torrent
+                .file_list
+                .as_deref()
+                .is_some_and(|list| list.contains("Track.flac"))
+        );
 
         // Assert - get_flacs works with fixture data
         let flacs = response.torrent.get_flacs();
@@ -5,7 +5,7 @@ use std::fs::File;
 use std::io::Read;
 
 /// Form data for uploading a torrent to a group
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct UploadForm {
     /// Path to the torrent file to upload
     pub path: PathBuf,
@@ -0,0 +1,56 @@
+use crate::prelude::*;
+
+/// A single entry in a [`UserTorrentsResponse`].
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTorrent {
+    /// Group ID
+    #[serde(deserialize_with = "string_or_u32")]
+    pub group_id: u32,
+    /// Group (album) name
+    #[serde(deserialize_with = "decode_entities")]
+    pub name: String,
+    /// Torrent ID
+    #[serde(deserialize_with = "string_or_u32")]
+    pub torrent_id: u32,
+    /// Primary artist name
+    #[serde(deserialize_with = "decode_entities")]
+    pub artist_name: String,
+    /// Primary artist ID
+    #[serde(deserialize_with = "string_or_u32")]
+    pub artist_id: u32,
+}
+
+#[cfg(feature = "mock")]
+impl UserTorrent {
+    /// Create a mock [`UserTorrent`] for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        Self {
+            group_id: 123,
+            name: "Test Album".to_owned(),
+            torrent_id: 456,
+            artist_name: "Test Artist".to_owned(),
+            artist_id: 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod decode_tests {
+    use super::*;
+
+    #[test]
+    fn user_torrent_text_fields_decoded() {
+        let json = r#"{
+            "groupId": "1",
+            "name": "Rock &amp; Roll",
+            "torrentId": "2",
+            "artistName": "AC&#x2F;DC",
+            "artistId": "3"
+        }"#;
+        let torrent: UserTorrent = json_from_str(json).expect("fixture should deserialize");
+        assert_eq!(torrent.name, "Rock & Roll");
+        assert_eq!(torrent.artist_name, "AC/DC");
+    }
+}
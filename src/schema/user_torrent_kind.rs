@@ -0,0 +1,36 @@
+use crate::prelude::*;
+
+/// Torrent list of a user for the `user_torrents` action.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserTorrentKind {
+    /// Torrents currently seeding
+    Seeding,
+    /// Torrents currently leeching
+    Leeching,
+    /// Torrents uploaded
+    Uploaded,
+    /// Torrents snatched
+    Snatched,
+}
+
+impl UserTorrentKind {
+    /// Query parameter value for the Gazelle `type` parameter.
+    ///
+    /// Also the key of the list in the response.
+    #[must_use]
+    pub fn as_query(&self) -> &'static str {
+        match self {
+            Self::Seeding => "seeding",
+            Self::Leeching => "leeching",
+            Self::Uploaded => "uploaded",
+            Self::Snatched => "snatched",
+        }
+    }
+}
+
+impl Display for UserTorrentKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.as_query())
+    }
+}
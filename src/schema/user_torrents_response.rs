@@ -0,0 +1,79 @@
+use crate::prelude::*;
+
+/// Response for the `user_torrents` action
+///
+/// Only the list matching the requested [`UserTorrentKind`] is present.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct UserTorrentsResponse {
+    /// Torrents currently seeding
+    #[serde(default)]
+    pub seeding: Vec<UserTorrent>,
+    /// Torrents currently leeching
+    #[serde(default)]
+    pub leeching: Vec<UserTorrent>,
+    /// Torrents uploaded
+    #[serde(default)]
+    pub uploaded: Vec<UserTorrent>,
+    /// Torrents snatched
+    #[serde(default)]
+    pub snatched: Vec<UserTorrent>,
+}
+
+impl UserTorrentsResponse {
+    /// Take the list for a [`UserTorrentKind`].
+    #[must_use]
+    pub fn into_entries(self, kind: UserTorrentKind) -> Vec<UserTorrent> {
+        match kind {
+            UserTorrentKind::Seeding => self.seeding,
+            UserTorrentKind::Leeching => self.leeching,
+            UserTorrentKind::Uploaded => self.uploaded,
+            UserTorrentKind::Snatched => self.snatched,
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::indexing_slicing,
+    reason = "test assertions on known fixture data"
+)]
+mod tests {
+    use super::*;
+
+    const OPS_RESPONSE: &str = include_str!("../tests/fixtures/user_torrents_response_ops.json");
+    const RED_RESPONSE: &str = include_str!("../tests/fixtures/user_torrents_response_red.json");
+
+    #[test]
+    fn deserialize_ops_user_torrents_response() {
+        // Arrange & Act
+        let response: UserTorrentsResponse =
+            json_from_str(OPS_RESPONSE).expect("should deserialize");
+
+        // Assert - OPS returns numeric IDs
+        let entries = response.into_entries(UserTorrentKind::Snatched);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].group_id, 100_200);
+        assert_eq!(entries[0].torrent_id, 3_000_001);
+        assert_eq!(entries[0].artist_id, 1460);
+        assert_eq!(entries[1].name, "Mock Single & Remixes");
+    }
+
+    #[test]
+    fn deserialize_red_user_torrents_response() {
+        // Arrange & Act
+        let response: UserTorrentsResponse =
+            json_from_str(RED_RESPONSE).expect("should deserialize");
+
+        // Assert - Other lists are empty
+        assert!(response.snatched.is_empty());
+        assert!(response.uploaded.is_empty());
+
+        // Assert - RED returns string IDs
+        let entries = response.into_entries(UserTorrentKind::Seeding);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].group_id, 200_300);
+        assert_eq!(entries[0].torrent_id, 6_000_001);
+        assert_eq!(entries[0].artist_name, "Mock Band");
+        assert_eq!(entries[0].artist_id, 2470);
+    }
+}
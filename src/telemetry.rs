@@ -0,0 +1,214 @@
+use crate::prelude::*;
+use metrics::{Unit, counter, describe_counter, describe_histogram, histogram};
+use std::time::Instant;
+use tracing::field::Empty;
+use tracing::{Instrument, Span, info_span};
+
+/// Counter of responses by `action`, `host` and `status`.
+///
+/// - `status` is `none` if no response was received
+pub const REQUESTS_METRIC: &str = "gazelle_api_requests_total";
+
+/// Histogram of the seconds from sending a request until its response was read,
+/// by `action` and `host`.
+pub const REQUEST_DURATION_METRIC: &str = "gazelle_api_request_duration_seconds";
+
+/// Histogram of the seconds waited for the rate limiter, by `host`.
+pub const RATE_LIMIT_WAIT_METRIC: &str = "gazelle_api_rate_limit_wait_seconds";
+
+/// Counter of retries by `host` and `class`.
+pub const RETRIES_METRIC: &str = "gazelle_api_retries_total";
+
+/// Counter of failed requests by `action`, `host` and `kind`.
+pub const ERRORS_METRIC: &str = "gazelle_api_errors_total";
+
+/// Describe the metrics to the installed [`metrics`] recorder.
+///
+/// - Call once after installing an exporter
+pub fn describe_metrics() {
+    describe_counter!(
+        REQUESTS_METRIC,
+        Unit::Count,
+        "Responses from the Gazelle API"
+    );
+    describe_histogram!(
+        REQUEST_DURATION_METRIC,
+        Unit::Seconds,
+        "Time from sending a request until its response was read"
+    );
+    describe_histogram!(
+        RATE_LIMIT_WAIT_METRIC,
+        Unit::Seconds,
+        "Time waited for the rate limiter before a request"
+    );
+    describe_counter!(RETRIES_METRIC, Unit::Count, "Retries of failed requests");
+    describe_counter!(ERRORS_METRIC, Unit::Count, "Failed requests by error kind");
+}
+
+impl GazelleClient {
+    /// Run an action in a span named `gazelle_api`.
+    ///
+    /// - `attempt` and `rate_limit_wait` are of the last request
+    /// - `elapsed` is the seconds for the whole action, including retries
+    pub(crate) async fn instrument_action<T>(
+        &self,
+        action: &'static str,
+        future: impl Future<Output = Result<T, GazelleError>>,
+    ) -> Result<T, GazelleError> {
+        let span = info_span!(
+            "gazelle_api",
+            action,
+            host = self.host(),
+            attempt = Empty,
+            rate_limit_wait = Empty,
+            elapsed = Empty,
+            error = Empty,
+        );
+        let start = Instant::now();
+        let result = future.instrument(span.clone()).await;
+        span.record("elapsed", start.elapsed().as_secs_f64());
+        if let Err(error) = &result {
+            span.record("error", get_error_kind(error));
+        }
+        result
+    }
+
+    /// Record the 0-indexed attempt number on the current span.
+    pub(crate) fn record_attempt(attempt: usize) {
+        Span::current().record("attempt", attempt);
+    }
+
+    /// Record the wait for the rate limiter.
+    pub(crate) fn record_rate_limit_wait(&self, wait: Duration) {
+        let wait = wait.as_secs_f64();
+        Span::current().record("rate_limit_wait", wait);
+        histogram!(RATE_LIMIT_WAIT_METRIC, "host" => self.host().to_owned()).record(wait);
+    }
+
+    /// Record that a failed request will be retried.
+    pub(crate) fn record_retry(&self, error: &GazelleError) {
+        let class = match error.retry_class() {
+            Some(RetryClass::RateLimited) => "rate_limited",
+            Some(RetryClass::ServerError) => "server_error",
+            Some(RetryClass::Transport) => "transport",
+            None => "other",
+        };
+        counter!(RETRIES_METRIC, "host" => self.host().to_owned(), "class" => class).increment(1);
+    }
+
+    /// Record the response of a request.
+    pub(crate) fn record_response(
+        &self,
+        request: &MiddlewareRequest,
+        response: &MiddlewareResponse<'_>,
+    ) {
+        let action = request.action().unwrap_or_default().to_owned();
+        let host = self.host().to_owned();
+        let status = response
+            .status
+            .map_or_else(|| "none".to_owned(), |status| status.as_u16().to_string());
+        counter!(
+            REQUESTS_METRIC,
+            "action" => action.clone(),
+            "host" => host.clone(),
+            "status" => status
+        )
+        .increment(1);
+        histogram!(
+            REQUEST_DURATION_METRIC,
+            "action" => action.clone(),
+            "host" => host.clone()
+        )
+        .record(response.elapsed.as_secs_f64());
+        if let Some(error) = response.error {
+            counter!(
+                ERRORS_METRIC,
+                "action" => action,
+                "host" => host,
+                "kind" => get_error_kind(error)
+            )
+            .increment(1);
+        }
+    }
+}
+
+/// Name of the error kind for labels.
+///
+/// Example: `ApiResponse(NotFound)`
+fn get_error_kind(error: &GazelleError) -> String {
+    format!("{:?}", error.operation)
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use metrics::SharedString;
+    use metrics::with_local_recorder;
+    use metrics_util::CompositeKey;
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    type Metric = (CompositeKey, Option<Unit>, Option<SharedString>, DebugValue);
+    use tokio::runtime::Builder as RuntimeBuilder;
+
+    /// Sum of the counter with the name whose labels include `labels`.
+    fn get_counter(metrics: &[Metric], name: &str, labels: &[(&str, &str)]) -> u64 {
+        metrics
+            .iter()
+            .filter(|(key, ..)| key.key().name() == name)
+            .filter(|(key, ..)| {
+                labels.iter().all(|(label, value)| {
+                    key.key()
+                        .labels()
+                        .any(|actual| actual.key() == *label && actual.value() == *value)
+                })
+            })
+            .map(|(.., value)| match value {
+                DebugValue::Counter(count) => *count,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    #[test]
+    fn metrics_count_requests_retries_and_errors() {
+        // Arrange
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let runtime = RuntimeBuilder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("runtime should build");
+        let server = MockGazelleServer::start(MockIndexer::Red).expect("server should start");
+        server.rate_limit(1);
+        let client = GazelleClient::from(GazelleClientOptions {
+            retry_delays: vec![Duration::from_millis(1)],
+            ..server.options()
+        });
+
+        // Act
+        with_local_recorder(&recorder, || {
+            runtime.block_on(async {
+                let _ = client.get_index().await;
+                let _ = client.get_torrent(u32::MAX).await;
+            });
+        });
+
+        // Assert
+        let metrics = snapshotter.snapshot().into_vec();
+        let host = client.host();
+        let requests = |action, status| {
+            get_counter(
+                &metrics,
+                REQUESTS_METRIC,
+                &[("action", action), ("host", host), ("status", status)],
+            )
+        };
+        assert_eq!(requests("index", "429"), 1);
+        assert_eq!(requests("index", "200"), 1);
+        assert_eq!(requests("torrent", "400"), 1);
+        let retries = [("host", host), ("class", "rate_limited")];
+        assert_eq!(get_counter(&metrics, RETRIES_METRIC, &retries), 1);
+        let errors = [("action", "torrent"), ("kind", "ApiResponse(BadRequest)")];
+        assert_eq!(get_counter(&metrics, ERRORS_METRIC, &errors), 1);
+    }
+}
@@ -1,10 +1,12 @@
 pub(crate) use config::*;
 pub(crate) use for_each_indexer::*;
 pub(crate) use logging::*;
+pub(crate) use options::*;
 pub(crate) use shared_clients::*;
 
 mod config;
 mod for_each_indexer;
 mod logging;
+mod options;
 mod rate_limiter_tests;
 mod shared_clients;
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+
+/// Default [`GazelleClientOptions`] for tests that don't need API credentials.
+pub fn get_test_options(url: &str) -> GazelleClientOptions {
+    GazelleClientOptions {
+        user_agent: "test".to_owned(),
+        key: "test".to_owned(),
+        url: url.to_owned(),
+        requests_allowed_per_duration: None,
+        request_limit_duration: None,
+        rate_limit_path: None,
+        adaptive_rate: None,
+        retry_delays: Vec::new(),
+        retry_policy: None,
+        retry_uploads: false,
+        priority: RequestPriority::default(),
+        timeout: None,
+        connect_timeout: None,
+        read_timeout: None,
+        proxy: None,
+        root_certificates: Vec::new(),
+        pool_idle_timeout: None,
+        pool_max_idle_per_host: None,
+        cassette: None,
+    }
+}
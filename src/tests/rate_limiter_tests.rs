@@ -1,11 +1,16 @@
 use crate::prelude::*;
-use std::time::{Duration, SystemTime};
+use std::sync::Arc;
+use std::sync::Mutex as SyncMutex;
+use std::time::Duration;
+use tokio::spawn;
+use tokio::sync::Mutex;
+use tokio::time::{Instant, advance};
 
 const LIMIT_COUNT: usize = 5;
 const LIMIT_DURATION: Duration = Duration::from_secs(10);
 const LIMIT_DURATION_SHORT: Duration = Duration::from_millis(250);
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn test_get_wait_duration_empty() {
     // Arrange
     let limiter = RateLimiter::new(LIMIT_COUNT, LIMIT_DURATION);
@@ -18,13 +23,13 @@ async fn test_get_wait_duration_empty() {
     assert!(wait.is_none());
 }
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn test_get_wait_duration_available() {
     // Arrange
-    let limiter = RateLimiter::new(LIMIT_COUNT, LIMIT_DURATION);
-    let now = SystemTime::now();
+    let (limiter, store) = create_limiter(LIMIT_COUNT, LIMIT_DURATION);
+    let now = Instant::now();
     {
-        let mut requests = limiter.requests.lock().await;
+        let mut requests = store.requests.lock().await;
         for _ in 0..(LIMIT_COUNT - 1) {
             requests.push_back(now);
         }
@@ -38,13 +43,13 @@ async fn test_get_wait_duration_available() {
     assert!(wait.is_none());
 }
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn test_get_wait_duration_full() {
     // Arrange
-    let limiter = RateLimiter::new(LIMIT_COUNT, LIMIT_DURATION);
-    let now = SystemTime::now();
+    let (limiter, store) = create_limiter(LIMIT_COUNT, LIMIT_DURATION);
+    let now = Instant::now();
     {
-        let mut requests = limiter.requests.lock().await;
+        let mut requests = store.requests.lock().await;
         for _ in 0..LIMIT_COUNT {
             requests.push_back(now);
         }
@@ -55,85 +60,61 @@ async fn test_get_wait_duration_full() {
     print_duration("Wait", wait);
 
     // Assert
-    assert!(wait.is_some());
-    assert!(approximately_equals(
-        wait.expect("full queue should require wait"),
-        LIMIT_DURATION,
-        Duration::from_millis(100)
-    ));
+    assert_eq!(wait, Some(LIMIT_DURATION));
 }
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn test_execute_available() {
     // Arrange
-    let limiter = RateLimiter::new(LIMIT_COUNT, LIMIT_DURATION);
-    let now = SystemTime::now();
+    let (limiter, store) = create_limiter(LIMIT_COUNT, LIMIT_DURATION);
+    let now = Instant::now();
     {
-        let mut requests = limiter.requests.lock().await;
+        let mut requests = store.requests.lock().await;
         for _ in 0..(LIMIT_COUNT - 1) {
             requests.push_back(now);
         }
     }
 
     // Act
-    let now = SystemTime::now();
-    let wait = limiter.execute().await;
-    let elapsed = now.elapsed().expect("elapsed should not fail");
+    let now = Instant::now();
+    let wait = limiter.execute(RequestPriority::Interactive).await;
+    let elapsed = now.elapsed();
     print_duration("Wait", wait);
     print_duration("Elapsed", Some(elapsed));
 
     // Assert
     assert!(wait.is_none());
-    assert!(approximately_equals(
-        elapsed,
-        Duration::from_secs(0),
-        Duration::from_millis(50)
-    ));
+    assert_eq!(elapsed, Duration::ZERO);
 }
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn test_execute_full() {
     // Arrange
-    let limiter = RateLimiter::new(LIMIT_COUNT, LIMIT_DURATION_SHORT);
-    let now = SystemTime::now();
+    let (limiter, store) = create_limiter(LIMIT_COUNT, LIMIT_DURATION_SHORT);
+    let now = Instant::now();
     {
-        let mut requests = limiter.requests.lock().await;
+        let mut requests = store.requests.lock().await;
         for _ in 0..LIMIT_COUNT {
             requests.push_back(now);
         }
     }
 
     // Act
-    let now = SystemTime::now();
-    let wait = limiter.execute().await;
-    let elapsed = now.elapsed().expect("elapsed should not fail");
+    let now = Instant::now();
+    let wait = limiter.execute(RequestPriority::Interactive).await;
+    let elapsed = now.elapsed();
     print_duration("Wait", wait);
     print_duration("Elapsed", Some(elapsed));
 
     // Assert
-    assert!(wait.is_some());
-    assert!(approximately_equals(
-        wait.expect("full queue should require wait"),
-        LIMIT_DURATION_SHORT,
-        Duration::from_millis(50)
-    ));
-    assert!(approximately_equals(
-        elapsed,
-        LIMIT_DURATION_SHORT,
-        Duration::from_millis(50)
-    ));
-}
-
-fn approximately_equals(d1: Duration, d2: Duration, tolerance: Duration) -> bool {
-    if d1 > d2 {
-        d1.checked_sub(d2)
-            .expect("subtraction should not underflow")
-            <= tolerance
-    } else {
-        d2.checked_sub(d1)
-            .expect("subtraction should not underflow")
-            <= tolerance
-    }
+    assert_eq!(wait, Some(LIMIT_DURATION_SHORT));
+    assert_eq!(elapsed, LIMIT_DURATION_SHORT);
+}
+
+fn create_limiter(num: usize, per: Duration) -> (RateLimiter, Arc<MemoryRateLimitStore>) {
+    let store = Arc::new(MemoryRateLimitStore::default());
+    let limiter = RateLimiter::with_store(num, per, store.clone());
+    (limiter, store)
 }
 
 fn print_duration(name: &str, duration: Option<Duration>) {
@@ -146,55 +127,57 @@ fn print_duration(name: &str, duration: Option<Duration>) {
 
 // Edge case tests
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn test_constructor_initializes_empty_queue() {
     // Arrange & Act
-    let limiter = RateLimiter::new(10, Duration::from_secs(60));
+    let (limiter, store) = create_limiter(10, Duration::from_mins(1));
 
     // Assert
-    assert!(limiter.requests.lock().await.is_empty());
+    assert!(store.requests.lock().await.is_empty());
     assert_eq!(limiter.rate.num, 10);
-    assert_eq!(limiter.rate.per, Duration::from_secs(60));
+    assert_eq!(limiter.rate.per, Duration::from_mins(1));
 }
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn test_remove_stale_clears_old_requests() {
     // Arrange
-    let limiter = RateLimiter::new(LIMIT_COUNT, Duration::from_millis(50));
-    let now = SystemTime::now();
-    // Add requests that are already stale
-    let old_time = now - Duration::from_millis(100);
+    let (limiter, store) = create_limiter(LIMIT_COUNT, Duration::from_millis(50));
+    let now = Instant::now();
     {
-        let mut requests = limiter.requests.lock().await;
+        let mut requests = store.requests.lock().await;
         for _ in 0..LIMIT_COUNT {
-            requests.push_back(old_time);
+            requests.push_back(now);
         }
         assert_eq!(requests.len(), LIMIT_COUNT);
     }
+    // Make the requests stale
+    advance(Duration::from_millis(100)).await;
 
     // Act - get_wait_duration calls remove_stale internally
     let wait = limiter.get_wait_duration().await;
 
     // Assert - stale requests should be removed, no wait needed
     assert!(wait.is_none());
-    assert!(limiter.requests.lock().await.is_empty());
+    assert!(store.requests.lock().await.is_empty());
 }
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn test_partial_stale_removal() {
     // Arrange
-    let limiter = RateLimiter::new(LIMIT_COUNT, Duration::from_millis(100));
-    let now = SystemTime::now();
+    let (limiter, store) = create_limiter(LIMIT_COUNT, Duration::from_millis(100));
 
     // Add some stale and some fresh requests
-    let stale_time = now - Duration::from_millis(200);
     {
-        let mut requests = limiter.requests.lock().await;
+        let mut requests = store.requests.lock().await;
         for _ in 0..3 {
-            requests.push_back(stale_time);
+            requests.push_back(Instant::now());
         }
+    }
+    advance(Duration::from_millis(200)).await;
+    {
+        let mut requests = store.requests.lock().await;
         for _ in 0..2 {
-            requests.push_back(now);
+            requests.push_back(Instant::now());
         }
         assert_eq!(requests.len(), 5);
     }
@@ -204,39 +187,260 @@ async fn test_partial_stale_removal() {
 
     // Assert - stale removed, only fresh remain, under limit so no wait
     assert!(wait.is_none());
-    assert_eq!(limiter.requests.lock().await.len(), 2);
+    assert_eq!(store.requests.lock().await.len(), 2);
 }
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn test_execute_adds_request_to_queue() {
     // Arrange
-    let limiter = RateLimiter::new(LIMIT_COUNT, LIMIT_DURATION);
-    assert!(limiter.requests.lock().await.is_empty());
+    let (limiter, store) = create_limiter(LIMIT_COUNT, LIMIT_DURATION);
+    assert!(store.requests.lock().await.is_empty());
 
     // Act
-    limiter.execute().await;
+    limiter.execute(RequestPriority::Interactive).await;
 
     // Assert
-    assert_eq!(limiter.requests.lock().await.len(), 1);
+    assert_eq!(store.requests.lock().await.len(), 1);
 }
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn test_multiple_executes_fill_queue() {
     // Arrange
-    let limiter = RateLimiter::new(LIMIT_COUNT, LIMIT_DURATION);
+    let (limiter, store) = create_limiter(LIMIT_COUNT, LIMIT_DURATION);
 
     // Act
     for _ in 0..(LIMIT_COUNT - 1) {
-        limiter.execute().await;
+        limiter.execute(RequestPriority::Interactive).await;
     }
 
     // Assert - queue should have LIMIT_COUNT - 1 entries
-    assert_eq!(limiter.requests.lock().await.len(), LIMIT_COUNT - 1);
+    assert_eq!(store.requests.lock().await.len(), LIMIT_COUNT - 1);
 
     // Act - one more should not require waiting
-    let wait = limiter.execute().await;
+    let wait = limiter.execute(RequestPriority::Interactive).await;
 
     // Assert
     assert!(wait.is_none());
-    assert_eq!(limiter.requests.lock().await.len(), LIMIT_COUNT);
+    assert_eq!(store.requests.lock().await.len(), LIMIT_COUNT);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_shared_store_counts_both_limiters() {
+    // Arrange
+    let (first, store) = create_limiter(LIMIT_COUNT, LIMIT_DURATION);
+    let second = RateLimiter::with_store(LIMIT_COUNT, LIMIT_DURATION, store.clone());
+
+    // Act
+    for _ in 0..LIMIT_COUNT {
+        first.execute(RequestPriority::Interactive).await;
+    }
+    let wait = second.get_wait_duration().await;
+
+    // Assert
+    assert!(wait.is_some());
+    assert_eq!(store.requests.lock().await.len(), LIMIT_COUNT);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_stats_counts_requests_and_waiting() {
+    // Arrange
+    let limiter = Arc::new(RateLimiter::new(LIMIT_COUNT, LIMIT_DURATION_SHORT));
+    for _ in 0..LIMIT_COUNT {
+        limiter.execute(RequestPriority::Interactive).await;
+    }
+    let waiter = limiter.clone();
+    let task = spawn(async move { waiter.execute(RequestPriority::Interactive).await });
+    tokio_sleep(Duration::from_millis(50)).await;
+
+    // Act
+    let stats = limiter.stats().await;
+
+    // Assert
+    assert_eq!(stats.waiting, 1);
+    assert_eq!(stats.requests, LIMIT_COUNT);
+    assert_eq!(stats.num, LIMIT_COUNT);
+    assert_eq!(stats.per, LIMIT_DURATION_SHORT);
+    task.await.expect("task should not panic");
+    assert_eq!(limiter.stats().await.waiting, 0);
+}
+
+/// Queue callers behind a full limiter and return the order they were served in.
+async fn get_served_order(priorities: &[RequestPriority]) -> Vec<usize> {
+    let limiter = Arc::new(RateLimiter::new(1, Duration::from_millis(20)));
+    limiter.execute(RequestPriority::Interactive).await;
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let tasks: Vec<_> = priorities
+        .iter()
+        .enumerate()
+        .map(|(index, &priority)| {
+            let limiter = limiter.clone();
+            let order = order.clone();
+            spawn(async move {
+                limiter.execute(priority).await;
+                order.lock().await.push(index);
+            })
+        })
+        .collect();
+    for task in tasks {
+        task.await.expect("task should not panic");
+    }
+    order.lock().await.clone()
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_execute_interactive_before_background() {
+    // Arrange
+    let priorities = [
+        RequestPriority::Background,
+        RequestPriority::Background,
+        RequestPriority::Interactive,
+    ];
+
+    // Act
+    let order = get_served_order(&priorities).await;
+
+    // Assert
+    assert_eq!(order, vec![2, 0, 1]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_execute_background_not_starved() {
+    // Arrange
+    let mut priorities = vec![RequestPriority::Background];
+    priorities.extend([RequestPriority::Interactive; 6]);
+
+    // Act
+    let order = get_served_order(&priorities).await;
+
+    // Assert
+    assert_eq!(order, vec![1, 2, 3, 4, 0, 5, 6]);
+}
+
+fn create_adaptive_limiter(num: usize, per: Duration) -> RateLimiter {
+    RateLimiter::new(num, per).with_adaptive(AdaptiveRate {
+        min_num: 2,
+        recover_after: 3,
+    })
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_adaptive_rate_limited_halves_rate() {
+    // Arrange
+    let limiter = create_adaptive_limiter(10, Duration::ZERO);
+
+    // Act
+    limiter.record_rate_limited();
+
+    // Assert
+    assert_eq!(limiter.get_rate().num, 5);
+    assert_eq!(limiter.stats().await.num, 5);
+}
+
+#[test]
+fn test_adaptive_rate_limited_once_per_duration() {
+    // Arrange
+    let limiter = create_adaptive_limiter(10, LIMIT_DURATION);
+
+    // Act
+    limiter.record_rate_limited();
+    limiter.record_rate_limited();
+
+    // Assert
+    assert_eq!(limiter.get_rate().num, 5);
+}
+
+#[test]
+fn test_adaptive_rate_limited_respects_min() {
+    // Arrange
+    let limiter = create_adaptive_limiter(10, Duration::ZERO);
+
+    // Act
+    for _ in 0..5 {
+        limiter.record_rate_limited();
+    }
+
+    // Assert
+    assert_eq!(limiter.get_rate().num, 2);
+}
+
+#[test]
+fn test_adaptive_success_recovers_to_configured_rate() {
+    // Arrange
+    let limiter = create_adaptive_limiter(4, Duration::ZERO);
+    limiter.record_rate_limited();
+    assert_eq!(limiter.get_rate().num, 2);
+
+    // Act & Assert
+    for _ in 0..2 {
+        limiter.record_success();
+    }
+    assert_eq!(limiter.get_rate().num, 2);
+    limiter.record_success();
+    assert_eq!(limiter.get_rate().num, 3);
+    for _ in 0..10 {
+        limiter.record_success();
+    }
+    assert_eq!(limiter.get_rate().num, 4);
+}
+
+#[test]
+fn test_adaptive_rate_limited_resets_successes() {
+    // Arrange
+    let limiter = create_adaptive_limiter(8, LIMIT_DURATION);
+    limiter.record_rate_limited();
+    limiter.record_success();
+    limiter.record_success();
+
+    // Act
+    limiter.record_rate_limited();
+    limiter.record_success();
+
+    // Assert
+    assert_eq!(limiter.get_rate().num, 4);
+}
+
+#[test]
+fn test_not_adaptive_ignores_feedback() {
+    // Arrange
+    let limiter = RateLimiter::new(LIMIT_COUNT, LIMIT_DURATION);
+
+    // Act
+    limiter.record_rate_limited();
+
+    // Assert
+    assert_eq!(limiter.get_rate().num, LIMIT_COUNT);
+}
+
+/// A [`Clock`] that only moves when told to.
+struct ManualClock(SyncMutex<Instant>);
+
+impl ManualClock {
+    fn advance(&self, duration: Duration) {
+        *self.0.lock().expect("lock should not be poisoned") += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        *self.0.lock().expect("lock should not be poisoned")
+    }
+}
+
+#[tokio::test]
+async fn test_with_clock_uses_injected_time() {
+    // Arrange
+    let clock = Arc::new(ManualClock(SyncMutex::new(Instant::now())));
+    let limiter = RateLimiter::with_clock(LIMIT_COUNT, LIMIT_DURATION, clock.clone());
+    for _ in 0..LIMIT_COUNT {
+        limiter.execute(RequestPriority::Interactive).await;
+    }
+
+    // Act
+    clock.advance(Duration::from_secs(4));
+    let wait = limiter.get_wait_duration().await;
+
+    // Assert
+    assert_eq!(wait, Some(Duration::from_secs(6)));
+    clock.advance(Duration::from_secs(6));
+    assert!(limiter.get_wait_duration().await.is_none());
 }
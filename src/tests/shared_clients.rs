@@ -1,14 +1,28 @@
 use crate::prelude::*;
+#[cfg(feature = "mock")]
+use std::env::var_os;
 use std::sync::OnceLock;
 use tokio::sync::Mutex;
 
 pub type SharedClient = GazelleClient;
 type SharedClients = HashMap<String, (Arc<Mutex<SharedClient>>, ExampleValues)>;
 
+/// Environment variable to run the integration tests against a [`MockGazelleServer`]
+/// for each indexer instead of the indexers in `config.yml`.
+#[cfg(feature = "mock")]
+const MOCK_VAR: &str = "GAZELLE_API_MOCK";
+
 static SHARED_CLIENTS: OnceLock<SharedClients> = OnceLock::new();
 
+#[cfg(feature = "mock")]
+static MOCK_SERVERS: OnceLock<Vec<(String, MockGazelleServer)>> = OnceLock::new();
+
 pub fn get_shared_clients() -> &'static SharedClients {
     SHARED_CLIENTS.get_or_init(|| {
+        #[cfg(feature = "mock")]
+        if var_os(MOCK_VAR).is_some() {
+            return get_mock_clients();
+        }
         load_config()
             .into_iter()
             .map(|(name, config)| {
@@ -18,3 +32,31 @@ pub fn get_shared_clients() -> &'static SharedClients {
             .collect()
     })
 }
+
+#[cfg(feature = "mock")]
+fn get_mock_clients() -> SharedClients {
+    let servers = MOCK_SERVERS.get_or_init(|| {
+        [("ops", MockIndexer::Ops), ("red", MockIndexer::Red)]
+            .into_iter()
+            .map(|(name, indexer)| {
+                let server = MockGazelleServer::start(indexer).expect("mock server should start");
+                (name.to_owned(), server)
+            })
+            .collect()
+    });
+    servers
+        .iter()
+        .map(|(name, server)| {
+            let client = GazelleClient::from(GazelleClientOptions {
+                requests_allowed_per_duration: Some(100),
+                ..server.options()
+            });
+            let examples = ExampleValues {
+                torrent: server.torrent_id(),
+                group: server.group_id(),
+                user: server.user_id(),
+            };
+            (name.clone(), (Arc::new(Mutex::new(client)), examples))
+        })
+        .collect()
+}